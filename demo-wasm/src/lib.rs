@@ -45,7 +45,7 @@ impl Demo {
         let active = self.timeline.tick::<f32>();
         self.active_flat.clear();
         for (id, progress) in active {
-            self.active_flat.push(id.0 as f32);
+            self.active_flat.push(id.index() as f32);
             self.active_flat.push(progress);
         }
     }
@@ -0,0 +1,532 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::float::Float;
+#[cfg(feature = "alloc")]
+use crate::keyframes::Keyframes;
+use crate::lerp::Lerp;
+use crate::loop_mode::PlayDirection;
+use crate::spring::SpringTween;
+use crate::state::TweenState;
+#[cfg(feature = "alloc")]
+use crate::timeline::{AnimationTimeline, Timeline};
+use crate::tween::Tween;
+#[cfg(feature = "alloc")]
+use crate::tween::{Parallel, Sequence, Stagger, TweenId};
+use crate::vector::VectorSpace;
+
+/// Anything that advances one tick at a time toward a value.
+///
+/// Implemented by every animation type in the crate, so `Sequence`, `Parallel` and `Stagger`
+/// can hold any mix of them, nested to any depth.
+///
+/// The inherent methods of the same names take precedence in method-call syntax; call
+/// `Animation::reset(&mut spring)` to reach the trait's reset on a `SpringTween`.
+pub trait Animation<F: Float> {
+    type Value;
+
+    /// Advance by one tick and return the current value.
+    fn tick(&mut self) -> Self::Value;
+
+    /// Advance by `n` ticks as one step and return the value reached. Types that report
+    /// events, like marker tracks, report every event of the step rather than the last tick's.
+    fn tick_n(&mut self, n: u32) -> Self::Value {
+        let mut value = None;
+        for _ in 0..n {
+            value = Some(self.tick());
+        }
+        value.unwrap_or_else(|| self.value())
+    }
+
+    /// Current value without advancing.
+    fn value(&self) -> Self::Value;
+
+    /// Normalized progress [0, 1] through the current iteration.
+    fn progress(&self) -> F;
+
+    /// Ticks for one iteration, or 0 when not known in advance.
+    fn total_duration(&self) -> u32;
+
+    /// Return to the initial state.
+    fn reset(&mut self);
+
+    fn state(&self) -> TweenState;
+
+    fn is_finished(&self) -> bool {
+        self.state() == TweenState::Finished
+    }
+
+    /// Current playback direction; always forward for animations that cannot reverse.
+    fn direction(&self) -> PlayDirection {
+        PlayDirection::Forward
+    }
+
+    /// Flip direction mid-flight, continuing from the current value. Does nothing for
+    /// animations that cannot reverse.
+    fn reverse(&mut self) {}
+
+    /// Reset and start from the beginning of a leg in `direction`, i.e. at the end when
+    /// backward. Animations that cannot reverse restart forward.
+    fn rewind(&mut self, direction: PlayDirection) {
+        let _ = direction;
+        self.reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Boxed child of a composition: an [`Animation`] that can also be cloned.
+pub(crate) trait DynAnimation<F: Float, T>: Animation<F, Value = T> {
+    fn clone_box(&self) -> Box<dyn DynAnimation<F, T>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<F, T, A> DynAnimation<F, T> for A
+where
+    F: Float,
+    A: Animation<F, Value = T> + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn DynAnimation<F, T>> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Float, T> Clone for Box<dyn DynAnimation<F, T>> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl<T: Lerp<F> + Clone, F: Float> Animation<F> for Tween<T, F> {
+    type Value = T;
+
+    fn tick(&mut self) -> T {
+        Tween::tick(self)
+    }
+
+    fn value(&self) -> T {
+        Tween::value(self)
+    }
+
+    fn progress(&self) -> F {
+        Tween::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Tween::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Tween::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Tween::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Tween::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Tween::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Tween::rewind(self, direction);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Lerp<F> + Clone, F: Float> Animation<F> for Keyframes<T, F> {
+    type Value = T;
+
+    fn tick(&mut self) -> T {
+        Keyframes::tick(self)
+    }
+
+    fn tick_n(&mut self, n: u32) -> T {
+        Keyframes::tick_n(self, n)
+    }
+
+    fn value(&self) -> T {
+        Keyframes::value(self)
+    }
+
+    fn progress(&self) -> F {
+        Keyframes::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Keyframes::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Keyframes::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Keyframes::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Keyframes::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Keyframes::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Keyframes::rewind(self, direction);
+    }
+}
+
+/// Springs have no fixed duration: `total_duration` is 0, progress is the fraction of the
+/// distance to the target covered, and the spring is finished once at rest.
+impl<F: Float, T: VectorSpace<F>> Animation<F> for SpringTween<F, T> {
+    type Value = T;
+
+    fn tick(&mut self) -> T {
+        SpringTween::tick(self)
+    }
+
+    fn value(&self) -> T {
+        SpringTween::value(self)
+    }
+
+    fn progress(&self) -> F {
+        SpringTween::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        0
+    }
+
+    fn reset(&mut self) {
+        SpringTween::restart(self);
+    }
+
+    fn state(&self) -> TweenState {
+        if self.is_at_rest() {
+            TweenState::Finished
+        } else {
+            TweenState::Playing
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Animation<F> for Sequence<T, F> {
+    type Value = T;
+
+    fn tick(&mut self) -> T {
+        Sequence::tick(self)
+    }
+
+    fn value(&self) -> T {
+        Sequence::value(self)
+    }
+
+    fn progress(&self) -> F {
+        Sequence::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Sequence::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Sequence::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Sequence::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Sequence::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Sequence::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Sequence::rewind(self, direction);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Animation<F> for Parallel<T, F> {
+    type Value = Vec<T>;
+
+    fn tick(&mut self) -> Vec<T> {
+        Parallel::tick(self)
+    }
+
+    fn value(&self) -> Vec<T> {
+        self.values()
+    }
+
+    fn progress(&self) -> F {
+        Parallel::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Parallel::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Parallel::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Parallel::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Parallel::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Parallel::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Parallel::rewind(self, direction);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Animation<F> for Stagger<T, F> {
+    type Value = Vec<T>;
+
+    fn tick(&mut self) -> Vec<T> {
+        Stagger::tick(self)
+    }
+
+    fn value(&self) -> Vec<T> {
+        self.values()
+    }
+
+    fn progress(&self) -> F {
+        Stagger::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Stagger::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Stagger::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Stagger::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Stagger::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Stagger::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Stagger::rewind(self, direction);
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// The value is the `(TweenId, progress)` list of active entries, as from [`Timeline::tick`].
+impl<F: Float> Animation<F> for Timeline {
+    type Value = Vec<(TweenId, F)>;
+
+    fn tick(&mut self) -> Self::Value {
+        Timeline::tick(self)
+    }
+
+    fn tick_n(&mut self, n: u32) -> Self::Value {
+        Timeline::tick_n(self, n)
+    }
+
+    fn value(&self) -> Self::Value {
+        self.active()
+    }
+
+    fn progress(&self) -> F {
+        Timeline::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Timeline::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Timeline::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Timeline::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Timeline::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Timeline::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Timeline::rewind(self, direction);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Animation<F> for AnimationTimeline<T, F> {
+    type Value = Vec<(TweenId, T)>;
+
+    fn tick(&mut self) -> Self::Value {
+        AnimationTimeline::tick(self)
+    }
+
+    fn tick_n(&mut self, n: u32) -> Self::Value {
+        AnimationTimeline::tick_n(self, n)
+    }
+
+    fn value(&self) -> Self::Value {
+        self.active()
+    }
+
+    fn progress(&self) -> F {
+        AnimationTimeline::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        AnimationTimeline::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        AnimationTimeline::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        AnimationTimeline::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        AnimationTimeline::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        AnimationTimeline::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        AnimationTimeline::rewind(self, direction);
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use alloc::vec;
+
+    use super::Animation;
+    use crate::keyframes::{Keyframe, Keyframes};
+    use crate::loop_mode::LoopMode;
+    use crate::spring::{SpringConfig, SpringTween};
+    use crate::state::TweenState;
+    use crate::tween::{Parallel, Sequence, Tween};
+
+    const EPS: f32 = 1e-4;
+
+    fn run<A: Animation<f32>>(animation: &mut A, max_ticks: u32) -> u32 {
+        let mut ticks = 0;
+        while !animation.is_finished() && ticks < max_ticks {
+            animation.tick();
+            ticks += 1;
+        }
+        ticks
+    }
+
+    #[test]
+    fn sequence_mixes_animation_types() {
+        let inner = Sequence::new()
+            .push(Tween::new(10.0f32, 20.0, 4))
+            .push(Tween::new(20.0, 30.0, 4));
+        let mut seq = Sequence::new()
+            .push(Keyframes::new(vec![
+                Keyframe::new(0.0f32, 0),
+                Keyframe::new(10.0, 5),
+            ]))
+            .push(inner)
+            .push(SpringTween::new(30.0f32, 0.0, SpringConfig::stiff()));
+        assert_eq!(seq.total_duration(), 13);
+
+        for _ in 0..5 {
+            seq.tick();
+        }
+        assert!((seq.value() - 10.0).abs() < EPS);
+        for _ in 0..6 {
+            seq.tick();
+        }
+        assert!((seq.value() - 25.0).abs() < EPS);
+
+        let ticks = run(&mut seq, 1000);
+        assert!(ticks < 1000);
+        assert_eq!(seq.value(), 0.0);
+    }
+
+    #[test]
+    fn compositions_nest_and_reset() {
+        let mut parallel = Parallel::new().push(Tween::new(0.0f32, 1.0, 4)).push(
+            Sequence::new()
+                .push(Tween::new(0.0f32, 1.0, 2))
+                .push(Tween::new(1.0, 2.0, 6)),
+        );
+        assert_eq!(parallel.total_duration(), 8);
+        for _ in 0..4 {
+            parallel.tick();
+        }
+        assert!((parallel.progress() - 0.5).abs() < EPS);
+        assert_eq!(run(&mut parallel, 100), 4);
+        assert_eq!(parallel.values(), [1.0, 2.0]);
+
+        Animation::reset(&mut parallel);
+        assert_eq!(parallel.state(), TweenState::Playing);
+        assert_eq!(parallel.values(), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn looping_sequence_of_sequences() {
+        let mut seq = Sequence::new()
+            .push(Sequence::new().push(Tween::new(0.0f32, 1.0, 3)))
+            .push(Keyframes::new(vec![
+                Keyframe::new(1.0f32, 0),
+                Keyframe::new(0.0, 3),
+            ]))
+            .with_loop(LoopMode::Count(2));
+        assert_eq!(run(&mut seq, 100), 12);
+        assert_eq!(seq.value(), 0.0);
+    }
+
+    #[test]
+    fn spring_progress_and_reset() {
+        let mut spring = SpringTween::new(0.0f32, 10.0, SpringConfig::gentle());
+        assert_eq!(Animation::<f32>::progress(&spring), 0.0);
+        for _ in 0..20 {
+            spring.tick();
+        }
+        let progress = Animation::<f32>::progress(&spring);
+        assert!(progress > 0.0 && progress <= 1.0);
+        run(&mut spring, 2000);
+        assert_eq!(Animation::<f32>::progress(&spring), 1.0);
+
+        Animation::<f32>::reset(&mut spring);
+        assert_eq!(spring.value(), 0.0);
+        assert_eq!(Animation::<f32>::state(&spring), TweenState::Playing);
+    }
+}
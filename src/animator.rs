@@ -0,0 +1,313 @@
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::animation::Animation;
+use crate::error::TweenError;
+use crate::float::Float;
+use crate::slots::Slots;
+use crate::tween::TweenId;
+
+/// What [`Animator::tick_all`] does with animations that finish.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FinishPolicy {
+    /// Drop them; their ids stop working.
+    #[default]
+    Remove,
+    /// Keep them, holding their final value, until removed.
+    Retain,
+}
+
+/// A registry that owns many animations of one type and ticks them together.
+///
+/// Animations are stored densely, one array per field, so [`tick_all`](Self::tick_all) walks
+/// contiguous memory. Removal swaps the last animation into the freed place, so iteration
+/// order is not insertion order. For mixed animation types, use an enum implementing
+/// [`Animation`].
+#[derive(Clone, Debug)]
+pub struct Animator<A, F> {
+    animations: Vec<A>,
+    ids: Vec<TweenId>,
+    tags: Vec<u32>,
+    paused: Vec<bool>,
+    slots: Slots,
+    policy: FinishPolicy,
+    /// Finished during the latest `tick_all`.
+    finished: Vec<TweenId>,
+    _float: PhantomData<F>,
+}
+
+impl<A: Animation<F>, F: Float> Animator<A, F> {
+    pub fn new() -> Self {
+        Self {
+            animations: Vec::new(),
+            ids: Vec::new(),
+            tags: Vec::new(),
+            paused: Vec::new(),
+            slots: Slots::new(),
+            policy: FinishPolicy::Remove,
+            finished: Vec::new(),
+            _float: PhantomData,
+        }
+    }
+
+    pub fn with_finish_policy(mut self, policy: FinishPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn finish_policy(&self) -> FinishPolicy {
+        self.policy
+    }
+
+    /// Add an animation with tag 0. Returns the id for lookup.
+    pub fn insert(&mut self, animation: A) -> TweenId {
+        self.insert_tagged(animation, 0)
+    }
+
+    /// Add an animation to a group, e.g. all UI or all enemy tweens, for bulk operations.
+    pub fn insert_tagged(&mut self, animation: A, tag: u32) -> TweenId {
+        let id = self.slots.insert(self.animations.len());
+        self.animations.push(animation);
+        self.ids.push(id);
+        self.tags.push(tag);
+        self.paused.push(false);
+        id
+    }
+
+    /// Remove an animation and hand it back. Its id, and any copies of it, stop working.
+    pub fn remove(&mut self, id: TweenId) -> Result<A, TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        Ok(self.remove_at(position))
+    }
+
+    /// Remove and drop an animation wherever it is in its playback.
+    pub fn cancel(&mut self, id: TweenId) -> Result<(), TweenError> {
+        self.remove(id).map(drop)
+    }
+
+    /// Cancel every animation with `tag`. Returns how many were cancelled.
+    pub fn cancel_tag(&mut self, tag: u32) -> usize {
+        let before = self.animations.len();
+        for position in (0..before).rev() {
+            if self.tags[position] == tag {
+                self.remove_at(position);
+            }
+        }
+        before - self.animations.len()
+    }
+
+    /// Remove every animation, invalidating all ids.
+    pub fn clear(&mut self) {
+        while !self.animations.is_empty() {
+            self.remove_at(self.animations.len() - 1);
+        }
+        self.finished.clear();
+    }
+
+    /// Stop ticking an animation until [`resume`](Self::resume).
+    pub fn pause(&mut self, id: TweenId) -> Result<(), TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.paused[position] = true;
+        Ok(())
+    }
+
+    pub fn resume(&mut self, id: TweenId) -> Result<(), TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.paused[position] = false;
+        Ok(())
+    }
+
+    pub fn pause_tag(&mut self, tag: u32) {
+        self.set_tag_paused(tag, true);
+    }
+
+    pub fn resume_tag(&mut self, tag: u32) {
+        self.set_tag_paused(tag, false);
+    }
+
+    /// Whether an animation is paused; `false` for unknown ids.
+    pub fn is_paused(&self, id: TweenId) -> bool {
+        self.position_of(id).is_some_and(|p| self.paused[p])
+    }
+
+    pub fn tag(&self, id: TweenId) -> Option<u32> {
+        self.position_of(id).map(|p| self.tags[p])
+    }
+
+    pub fn set_tag(&mut self, id: TweenId, tag: u32) -> Result<(), TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.tags[position] = tag;
+        Ok(())
+    }
+
+    /// Advance every running animation by one tick, then apply the [`FinishPolicy`] to the
+    /// ones that finished.
+    pub fn tick_all(&mut self) {
+        self.finished.clear();
+        for (position, animation) in self.animations.iter_mut().enumerate() {
+            if self.paused[position] || animation.is_finished() {
+                continue;
+            }
+            animation.tick();
+            if animation.is_finished() {
+                self.finished.push(self.ids[position]);
+            }
+        }
+        if self.policy == FinishPolicy::Remove {
+            for position in (0..self.animations.len()).rev() {
+                if self.animations[position].is_finished() {
+                    self.remove_at(position);
+                }
+            }
+        }
+    }
+
+    /// Ids of the animations that finished during the latest [`tick_all`](Self::tick_all).
+    /// Under [`FinishPolicy::Remove`] they are already gone.
+    pub fn finished(&self) -> &[TweenId] {
+        &self.finished
+    }
+
+    pub fn get(&self, id: TweenId) -> Option<&A> {
+        self.position_of(id).map(|p| &self.animations[p])
+    }
+
+    pub fn get_mut(&mut self, id: TweenId) -> Option<&mut A> {
+        self.position_of(id).map(|p| &mut self.animations[p])
+    }
+
+    /// Current value of an animation.
+    pub fn value(&self, id: TweenId) -> Option<A::Value> {
+        self.get(id).map(A::value)
+    }
+
+    pub fn contains(&self, id: TweenId) -> bool {
+        self.position_of(id).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.animations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+
+    /// Every animation with its id, in storage order.
+    pub fn iter(&self) -> impl Iterator<Item = (TweenId, &A)> {
+        self.ids.iter().copied().zip(&self.animations)
+    }
+
+    /// Every animation's current value with its id, in storage order.
+    pub fn values(&self) -> impl Iterator<Item = (TweenId, A::Value)> + '_ {
+        self.iter().map(|(id, animation)| (id, animation.value()))
+    }
+
+    /// Every animation with `tag`, with its id.
+    pub fn iter_tag(&self, tag: u32) -> impl Iterator<Item = (TweenId, &A)> {
+        self.iter()
+            .zip(&self.tags)
+            .filter(move |(_, t)| **t == tag)
+            .map(|(entry, _)| entry)
+    }
+
+    fn set_tag_paused(&mut self, tag: u32, paused: bool) {
+        for (flag, _) in self
+            .paused
+            .iter_mut()
+            .zip(&self.tags)
+            .filter(|(_, t)| **t == tag)
+        {
+            *flag = paused;
+        }
+    }
+
+    /// Swap-remove the animation at `position` and retire its id.
+    fn remove_at(&mut self, position: usize) -> A {
+        let id = self.ids.swap_remove(position);
+        self.tags.swap_remove(position);
+        self.paused.swap_remove(position);
+        let animation = self.animations.swap_remove(position);
+        self.slots.remove(id);
+        if let Some(&moved) = self.ids.get(position) {
+            self.slots.moved(moved, position);
+        }
+        animation
+    }
+
+    /// Index of the animation behind `id` in the dense arrays.
+    fn position_of(&self, id: TweenId) -> Option<usize> {
+        self.slots.get(id)
+    }
+}
+
+impl<A: Animation<F>, F: Float> Default for Animator<A, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Animator, FinishPolicy};
+    use crate::error::TweenError;
+    use crate::tween::Tween;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn animator_ticks_and_removes_finished() {
+        let mut animator = Animator::new();
+        let short = animator.insert(Tween::new(0.0f32, 2.0, 2));
+        let long = animator.insert(Tween::new(0.0f32, 4.0, 4));
+        let paused = animator.insert(Tween::new(0.0f32, 1.0, 1));
+        animator.pause(paused).unwrap();
+
+        animator.tick_all();
+        animator.tick_all();
+        assert_eq!(animator.finished(), [short]);
+        assert!(!animator.contains(short));
+        assert_eq!(animator.value(long), Some(2.0));
+        assert_eq!(animator.value(paused), Some(0.0));
+        assert_eq!(animator.remove(short).unwrap_err(), TweenError::InvalidId);
+
+        let reused = animator.insert(Tween::new(5.0f32, 5.0, 1));
+        assert_eq!(reused.index(), short.index());
+        assert!(animator.get(short).is_none());
+
+        animator.resume(paused).unwrap();
+        animator.tick_all();
+        let mut finished = animator.finished().to_vec();
+        finished.sort_by_key(|id| id.index());
+        assert_eq!(finished, [reused, paused]);
+        assert_eq!(animator.len(), 1);
+        assert_eq!(animator.values().collect::<Vec<_>>(), [(long, 3.0)]);
+    }
+
+    #[test]
+    fn animator_retains_and_groups_by_tag() {
+        let mut animator = Animator::new().with_finish_policy(FinishPolicy::Retain);
+        let ui = animator.insert_tagged(Tween::new(0.0f32, 1.0, 1), 1);
+        let enemies: Vec<_> = (0..3)
+            .map(|i| animator.insert_tagged(Tween::new(0.0f32, i as f32, 2), 2))
+            .collect();
+
+        animator.pause_tag(2);
+        animator.tick_all();
+        assert_eq!(animator.finished(), [ui]);
+        assert_eq!(animator.value(ui), Some(1.0));
+        assert!(enemies.iter().all(|&id| animator.is_paused(id)));
+
+        animator.resume_tag(2);
+        animator.set_tag(enemies[0], 1).unwrap();
+        assert_eq!(animator.iter_tag(2).count(), 2);
+        assert_eq!(animator.cancel_tag(2), 2);
+        assert_eq!(animator.len(), 2);
+        assert_eq!(animator.tag(enemies[0]), Some(1));
+        assert!(!animator.contains(enemies[1]));
+
+        animator.cancel(ui).unwrap();
+        assert_eq!(animator.cancel(ui), Err(TweenError::InvalidId));
+        animator.clear();
+        assert!(animator.is_empty() && !animator.contains(enemies[0]));
+    }
+}
@@ -0,0 +1,217 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::float::Float;
+use crate::keyframes::{Keyframe, Keyframes};
+use crate::lerp::Lerp;
+use crate::vector::VectorSpace;
+
+/// Sample `sample(tick)` every `interval` ticks from 0 through `duration` into linear keyframes.
+///
+/// The final tick is always sampled, even when `duration` is not a multiple of `interval`.
+/// Ticks are visited in increasing order. An `interval` of zero samples every tick.
+pub fn bake<T, F>(duration: u32, interval: u32, mut sample: impl FnMut(u32) -> T) -> Keyframes<T, F>
+where
+    T: Lerp<F> + Clone,
+    F: Float,
+{
+    let interval = interval.max(1);
+    let mut frames = Vec::with_capacity((duration / interval) as usize + 2);
+    let mut tick = 0;
+    loop {
+        frames.push(Keyframe::new(sample(tick), tick));
+        if tick >= duration {
+            break;
+        }
+        tick = tick.saturating_add(interval).min(duration);
+    }
+    Keyframes::new(frames)
+}
+
+/// Record a ticking animation: `initial` at tick 0, then one `step` per tick until it reports
+/// done or `max_ticks` is reached, keeping every `interval`-th value and the last one.
+pub(crate) fn record<T, F>(
+    initial: T,
+    interval: u32,
+    max_ticks: u32,
+    mut step: impl FnMut() -> (T, bool),
+) -> Keyframes<T, F>
+where
+    T: Lerp<F> + Clone,
+    F: Float,
+{
+    let interval = interval.max(1);
+    let mut frames = vec![Keyframe::new(initial, 0)];
+    for tick in 1..=max_ticks {
+        let (value, done) = step();
+        if done || tick == max_ticks || tick % interval == 0 {
+            frames.push(Keyframe::new(value, tick));
+        }
+        if done {
+            break;
+        }
+    }
+    Keyframes::new(frames)
+}
+
+/// Drop keys that linear interpolation between their neighbours reproduces within `tolerance`
+/// (Ramer-Douglas-Peucker over tick and value).
+///
+/// Only the key values are considered, so the source is best a dense linear track such as a
+/// [`bake`] result or recorded input. Every source key tick stays within `tolerance` of the
+/// result, which uses linear keys throughout and keeps the source's markers.
+pub fn simplify<T, F>(keyframes: &Keyframes<T, F>, tolerance: F) -> Keyframes<T, F>
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let keys = keyframes.keys();
+    let last = keys.len() - 1;
+    let mut keep = vec![false; keys.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut stack = vec![(0, last)];
+    while let Some((start, end)) = stack.pop() {
+        if end <= start + 1 {
+            continue;
+        }
+        let (a, b) = (&keys[start], &keys[end]);
+        let span = b.tick - a.tick;
+        let mut worst = (start, F::zero());
+        for (offset, key) in keys[start + 1..end].iter().enumerate() {
+            let t = if span == 0 {
+                F::zero()
+            } else {
+                F::from_f32((key.tick - a.tick) as f32 / span as f32)
+            };
+            let error = key.value.minus(&a.value.lerp(&b.value, t)).length();
+            if error > worst.1 {
+                worst = (start + 1 + offset, error);
+            }
+        }
+        if worst.1 > tolerance {
+            keep[worst.0] = true;
+            stack.push((start, worst.0));
+            stack.push((worst.0, end));
+        }
+    }
+
+    let frames = keys
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(key, _)| Keyframe::new(key.value.clone(), key.tick))
+        .collect();
+    Keyframes::new(frames).with_markers_of(keyframes)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{bake, simplify};
+    use crate::easing::Easing;
+    use crate::keyframes::{Keyframe, Keyframes};
+    use crate::marker::Marker;
+    use crate::spring::{SpringConfig, SpringTween};
+    use crate::tween::{Sequence, Tween};
+
+    fn max_error(a: &Keyframes<f32, f32>, b: &Keyframes<f32, f32>, ticks: u32) -> f32 {
+        (0..=ticks)
+            .map(|t| (a.sample(t) - b.sample(t)).abs())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn bake_matches_ticking() {
+        let tween = Tween::<f32, f32>::new(0.0, 100.0, 30)
+            .with_easing(Easing::EaseInOutCubic)
+            .with_delay(5);
+        let baked = tween.bake(1);
+        assert_eq!(baked.total_duration(), 35);
+
+        let mut live = tween.clone();
+        let mut expected = vec![live.value()];
+        expected.extend((0..35).map(|_| live.tick()));
+        for (tick, value) in expected.iter().enumerate() {
+            assert!((baked.sample(tick as u32) - value).abs() < 1e-5);
+        }
+
+        let coarse = tween.bake(4);
+        let ticks: Vec<u32> = coarse.keys().iter().map(|k| k.tick).collect();
+        assert_eq!(*ticks.last().unwrap(), 35);
+        assert_eq!(ticks[1], 4);
+    }
+
+    #[test]
+    fn bake_sequence_and_spring() {
+        let sequence = Sequence::new()
+            .push(Tween::<f32, f32>::new(0.0, 10.0, 10))
+            .push(Tween::new(10.0, -10.0, 20));
+        let baked = sequence.bake(1);
+        assert_eq!(baked.total_duration(), 30);
+        assert!((baked.sample(20) - 0.0).abs() < 1e-4);
+
+        let spring = SpringTween::new(0.0f32, 1.0, SpringConfig::stiff());
+        let baked = spring.bake(600, 2);
+        let end = baked.total_duration();
+        assert!(end < 600);
+        assert_eq!(baked.sample(end), 1.0);
+    }
+
+    #[test]
+    fn resample_keyframes() {
+        let source = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0),
+            Keyframe::new(10.0, 10).with_easing(Easing::EaseOutQuad),
+            Keyframe::new(0.0, 25),
+        ])
+        .with_marker(10, 7);
+        let resampled = source.resample(1);
+        assert_eq!(resampled.keys().len(), 26);
+        assert_eq!(resampled.markers(), [Marker::new(10, 7)]);
+        assert!(max_error(&source, &resampled, 25) < 1e-5);
+        let sparse: Keyframes<f32, f32> = bake(25, 10, |t| source.sample(t));
+        assert_eq!(sparse.keys().len(), 4);
+    }
+
+    #[test]
+    fn simplify_within_tolerance() {
+        let tween = Tween::<f32, f32>::new(0.0, 100.0, 240).with_easing(Easing::EaseInOutSine);
+        let dense = tween.bake(1);
+        for tolerance in [0.01f32, 0.1, 1.0] {
+            let reduced = simplify(&dense, tolerance);
+            assert!(reduced.keys().len() < dense.keys().len());
+            assert!(max_error(&dense, &reduced, 240) <= tolerance + 1e-4);
+        }
+        assert!(simplify(&dense, 1.0).keys().len() < 20);
+        let marked = dense.clone().with_marker(120, 3);
+        assert_eq!(simplify(&marked, 1.0).markers(), [Marker::new(120, 3)]);
+        assert!(simplify(&dense, 1.0).keys().len() < simplify(&dense, 0.01).keys().len());
+    }
+
+    #[test]
+    fn simplify_noisy_recording_and_vectors() {
+        // Deterministic jitter on a straight line collapses to its end points.
+        let noisy: Keyframes<f32, f32> =
+            bake(100, 1, |t| t as f32 + if t % 2 == 0 { 0.02 } else { -0.02 });
+        let reduced = simplify(&noisy, 0.05);
+        assert_eq!(reduced.keys().len(), 2);
+        assert!(max_error(&noisy, &reduced, 100) <= 0.05);
+
+        // A corner in 2D is kept.
+        let path: Keyframes<(f32, f32), f32> = bake(20, 1, |t| {
+            let t = t as f32;
+            if t <= 10.0 {
+                (t, 0.0)
+            } else {
+                (10.0, t - 10.0)
+            }
+        });
+        let reduced = simplify(&path, 0.01);
+        let ticks: Vec<u32> = reduced.keys().iter().map(|k| k.tick).collect();
+        assert_eq!(ticks, [0, 10, 20]);
+    }
+}
@@ -0,0 +1,428 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::float::Float;
+use crate::keyframes::Keyframes;
+use crate::lerp::Lerp;
+use crate::loop_mode::{LoopMode, PlayDirection};
+use crate::state::TweenState;
+
+/// Writes a sampled track value into a property of `S`.
+///
+/// Implemented for any `Fn(&mut S, T)` closure; implement it on a marker type to name a
+/// property once and reuse it across clips.
+pub trait TrackTarget<S, T> {
+    fn apply(&self, target: &mut S, value: T);
+}
+
+impl<S, T, G: Fn(&mut S, T)> TrackTarget<S, T> for G {
+    fn apply(&self, target: &mut S, value: T) {
+        self(target, value)
+    }
+}
+
+/// A keyframe track with its value type erased behind the target it writes to.
+trait ClipTrack<S> {
+    fn duration(&self) -> u32;
+    fn apply_at(&self, tick: u32, target: &mut S);
+}
+
+struct BoundTrack<T: Lerp<F>, F: Float, B> {
+    keyframes: Keyframes<T, F>,
+    binding: B,
+    _target: PhantomData<fn(&mut T)>,
+}
+
+impl<S, T, F, B> ClipTrack<S> for BoundTrack<T, F, B>
+where
+    T: Lerp<F> + Clone,
+    F: Float,
+    B: TrackTarget<S, T>,
+{
+    fn duration(&self) -> u32 {
+        self.keyframes.total_duration()
+    }
+
+    fn apply_at(&self, tick: u32, target: &mut S) {
+        self.binding.apply(target, self.keyframes.sample(tick));
+    }
+}
+
+/// Several keyframe tracks of different value types played on one clock.
+///
+/// Each track is sampled at the clip's elapsed tick; the tracks' own playback state and loop
+/// modes are ignored. The clip lasts as long as its longest track.
+pub struct AnimationClip<S> {
+    tracks: Vec<(String, Box<dyn ClipTrack<S>>)>,
+    elapsed: u32,
+    state: TweenState,
+    loop_mode: LoopMode,
+    loops_completed: u32,
+    direction: PlayDirection,
+}
+
+impl<S> AnimationClip<S> {
+    pub fn new() -> Self {
+        Self {
+            tracks: Vec::new(),
+            elapsed: 0,
+            state: TweenState::Playing,
+            loop_mode: LoopMode::Once,
+            loops_completed: 0,
+            direction: PlayDirection::Forward,
+        }
+    }
+
+    pub fn with_loop(mut self, mode: LoopMode) -> Self {
+        self.loop_mode = mode;
+        self
+    }
+
+    /// Add a track bound to a property of `S`. Returns the track index.
+    pub fn add_track<T, F, B>(
+        &mut self,
+        name: impl Into<String>,
+        keyframes: Keyframes<T, F>,
+        binding: B,
+    ) -> usize
+    where
+        T: Lerp<F> + Clone + 'static,
+        F: Float + 'static,
+        B: TrackTarget<S, T> + 'static,
+    {
+        self.tracks.push((
+            name.into(),
+            Box::new(BoundTrack {
+                keyframes,
+                binding,
+                _target: PhantomData,
+            }),
+        ));
+        self.tracks.len() - 1
+    }
+
+    /// Builder form of [`add_track`](Self::add_track).
+    pub fn with_track<T, F, B>(
+        mut self,
+        name: impl Into<String>,
+        keyframes: Keyframes<T, F>,
+        binding: B,
+    ) -> Self
+    where
+        T: Lerp<F> + Clone + 'static,
+        F: Float + 'static,
+        B: TrackTarget<S, T> + 'static,
+    {
+        self.add_track(name, keyframes, binding);
+        self
+    }
+
+    pub fn track_count(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// Index of the first track called `name`.
+    pub fn track_index(&self, name: &str) -> Option<usize> {
+        self.tracks.iter().position(|(track, _)| track == name)
+    }
+
+    pub fn track_name(&self, index: usize) -> Option<&str> {
+        self.tracks.get(index).map(|(name, _)| name.as_str())
+    }
+
+    /// Advance by one tick and write every track into `target`.
+    pub fn tick(&mut self, target: &mut S) {
+        if self.state != TweenState::Playing {
+            self.apply(target);
+            return;
+        }
+
+        let total = self.total_duration();
+        if total > 0 && self.elapsed < total {
+            self.elapsed += 1;
+        }
+
+        self.apply(target);
+        if self.elapsed >= total {
+            self.on_iteration_complete();
+        }
+    }
+
+    /// Write every track's value at the current tick into `target`.
+    pub fn apply(&self, target: &mut S) {
+        self.sample(self.elapsed(), target);
+    }
+
+    /// Write every track's value at `tick` into `target` without moving the playhead.
+    pub fn sample(&self, tick: u32, target: &mut S) {
+        for (_, track) in &self.tracks {
+            track.apply_at(tick, target);
+        }
+    }
+
+    /// Write a single track's value at the current tick into `target`.
+    pub fn apply_track(&self, index: usize, target: &mut S) {
+        if let Some((_, track)) = self.tracks.get(index) {
+            track.apply_at(self.elapsed(), target);
+        }
+    }
+
+    /// Duration of the longest track.
+    pub fn total_duration(&self) -> u32 {
+        self.tracks
+            .iter()
+            .map(|(_, track)| track.duration())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Tick on the clip the playhead is at.
+    pub fn elapsed(&self) -> u32 {
+        match self.direction {
+            PlayDirection::Forward => self.elapsed,
+            PlayDirection::Backward => self.total_duration().saturating_sub(self.elapsed),
+        }
+    }
+
+    pub fn progress<F: Float>(&self) -> F {
+        let total = self.total_duration();
+        if total == 0 {
+            return F::one();
+        }
+        F::from_f32(self.elapsed().min(total) as f32 / total as f32)
+    }
+
+    /// Seek to a specific tick, keeping the current direction.
+    pub fn seek(&mut self, tick: u32) {
+        let total = self.total_duration();
+        self.elapsed = match self.direction {
+            PlayDirection::Forward => tick,
+            PlayDirection::Backward => total.saturating_sub(tick),
+        };
+        if self.elapsed < total {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Current playback direction. Backward legs sample every track from its end.
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current tick.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight, continuing from the current tick. A finished clip plays back
+    /// the way it came.
+    pub fn reverse(&mut self) {
+        self.direction = self.direction.reversed();
+        let total = self.total_duration();
+        self.elapsed = total - self.elapsed.min(total);
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == TweenState::Finished
+    }
+
+    pub fn loops_completed(&self) -> u32 {
+        self.loops_completed
+    }
+
+    pub fn reset(&mut self) {
+        self.elapsed = 0;
+        self.loops_completed = 0;
+        self.direction = PlayDirection::Forward;
+        self.state = TweenState::Playing;
+    }
+
+    fn on_iteration_complete(&mut self) {
+        match self.loop_mode {
+            LoopMode::Once => {
+                self.state = TweenState::Finished;
+            }
+            LoopMode::Count(count) => {
+                self.loops_completed += 1;
+                if count == 0 || self.loops_completed >= count {
+                    self.state = TweenState::Finished;
+                } else {
+                    self.elapsed = 0;
+                }
+            }
+            LoopMode::Infinite => {
+                self.loops_completed += 1;
+                self.elapsed = 0;
+            }
+            LoopMode::PingPong => {
+                self.loops_completed += 1;
+                self.elapsed = 0;
+                self.direction = self.direction.reversed();
+            }
+            LoopMode::PingPongCount(count) => {
+                self.loops_completed += 1;
+                let max_legs = count.saturating_mul(2);
+                if max_legs == 0 || self.loops_completed >= max_legs {
+                    self.state = TweenState::Finished;
+                } else {
+                    self.elapsed = 0;
+                    self.direction = self.direction.reversed();
+                }
+            }
+        }
+    }
+}
+
+impl<S> Default for AnimationClip<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> fmt::Debug for AnimationClip<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimationClip")
+            .field(
+                "tracks",
+                &self
+                    .tracks
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>(),
+            )
+            .field("elapsed", &self.elapsed)
+            .field("state", &self.state)
+            .field("loop_mode", &self.loop_mode)
+            .field("loops_completed", &self.loops_completed)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::clip::{AnimationClip, TrackTarget};
+    use crate::keyframes::{Keyframe, Keyframes};
+    use crate::lerp::Rgba;
+    use crate::loop_mode::LoopMode;
+
+    const EPS: f32 = 1e-4;
+
+    #[derive(Default)]
+    struct Sprite {
+        position: (f32, f32),
+        color: Rgba<f32>,
+        frame: u32,
+    }
+
+    struct Position;
+
+    impl TrackTarget<Sprite, (f32, f32)> for Position {
+        fn apply(&self, target: &mut Sprite, value: (f32, f32)) {
+            target.position = value;
+        }
+    }
+
+    fn clip() -> AnimationClip<Sprite> {
+        AnimationClip::new()
+            .with_track(
+                "position",
+                Keyframes::new(vec![
+                    Keyframe::new((0.0f32, 0.0f32), 0),
+                    Keyframe::new((10.0, 20.0), 10),
+                ]),
+                Position,
+            )
+            .with_track(
+                "color",
+                Keyframes::<_, f32>::new(vec![
+                    Keyframe::new(Rgba::new(0.0f32, 0.0, 0.0, 1.0), 0),
+                    Keyframe::new(Rgba::new(1.0, 1.0, 1.0, 1.0), 20),
+                ]),
+                |sprite: &mut Sprite, color| sprite.color = color,
+            )
+            .with_track(
+                "frame",
+                Keyframes::<u32, f32>::new(vec![Keyframe::new(0u32, 0), Keyframe::new(4u32, 4)]),
+                |sprite: &mut Sprite, frame| sprite.frame = frame,
+            )
+    }
+
+    #[test]
+    fn clip_drives_all_tracks() {
+        let mut clip = clip();
+        let mut sprite = Sprite::default();
+        assert_eq!(clip.total_duration(), 20);
+        for _ in 0..5 {
+            clip.tick(&mut sprite);
+        }
+        assert!((sprite.position.0 - 5.0).abs() < EPS);
+        assert!((sprite.position.1 - 10.0).abs() < EPS);
+        assert!((sprite.color.r - 0.25).abs() < EPS);
+        assert_eq!(sprite.frame, 4);
+
+        for _ in 0..15 {
+            clip.tick(&mut sprite);
+        }
+        assert!(clip.is_finished());
+        assert!((sprite.color.r - 1.0).abs() < EPS);
+        assert!((sprite.position.0 - 10.0).abs() < EPS);
+    }
+
+    #[test]
+    fn clip_tracks_by_name() {
+        let clip = clip();
+        assert_eq!(clip.track_count(), 3);
+        assert_eq!(clip.track_index("color"), Some(1));
+        assert_eq!(clip.track_name(2), Some("frame"));
+        assert_eq!(clip.track_index("scale"), None);
+
+        let mut sprite = Sprite::default();
+        clip.sample(10, &mut sprite);
+        assert!((sprite.color.g - 0.5).abs() < EPS);
+    }
+
+    #[test]
+    fn clip_loops_on_shared_clock() {
+        let mut clip = clip().with_loop(LoopMode::Count(2));
+        let mut sprite = Sprite::default();
+        for _ in 0..25 {
+            clip.tick(&mut sprite);
+        }
+        assert!(!clip.is_finished());
+        assert_eq!(clip.loops_completed(), 1);
+        assert!((sprite.position.0 - 5.0).abs() < EPS);
+        for _ in 0..15 {
+            clip.tick(&mut sprite);
+        }
+        assert!(clip.is_finished());
+    }
+
+    #[test]
+    fn clip_ping_pong_plays_backward() {
+        let mut clip = clip().with_loop(LoopMode::PingPongCount(1));
+        let mut sprite = Sprite::default();
+        for _ in 0..25 {
+            clip.tick(&mut sprite);
+        }
+        assert_eq!(clip.elapsed(), 15);
+        assert!((sprite.color.r - 0.75).abs() < EPS);
+        assert!((sprite.position.0 - 10.0).abs() < EPS);
+        for _ in 0..15 {
+            clip.tick(&mut sprite);
+        }
+        assert!(clip.is_finished());
+        assert!(sprite.color.r.abs() < EPS);
+        assert_eq!(sprite.frame, 0);
+    }
+}
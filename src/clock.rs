@@ -0,0 +1,224 @@
+use crate::animation::Animation;
+use crate::float::Float;
+
+/// A scalable source of ticks for driving animations, e.g. a world, gameplay or UI clock.
+///
+/// Each frame, [`advance`](Self::advance) a root clock and then
+/// [`advance_from`](Self::advance_from) each child with its parent, parents first. A clock
+/// runs at its own time scale times its parent's effective rate, so pausing or slowing a
+/// parent pauses or slows every clock below it, while clocks on another root keep running.
+/// Fractional rates carry over between frames: at 0.5 a clock produces a tick every other frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clock {
+    time_scale: f32,
+    paused: bool,
+    /// Ticks per frame in the last advance, including every ancestor's scale.
+    rate: f64,
+    /// Fraction of a tick carried into the next advance.
+    carry: f64,
+    due: u32,
+    elapsed: u32,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Self {
+            time_scale: 1.0,
+            paused: false,
+            rate: 0.0,
+            carry: 0.0,
+            due: 0,
+            elapsed: 0,
+        }
+    }
+
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.set_time_scale(time_scale);
+        self
+    }
+
+    /// Ticks per parent tick: 0.5 for slow motion, 0.0 for hit-stop. Negative values count as 0.
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0);
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Stop producing ticks, for this clock and every clock advanced from it.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Continue from the fraction of a tick the clock was paused at.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Advance a root clock by one frame. Returns the ticks now due.
+    pub fn advance(&mut self) -> u32 {
+        self.step(1.0)
+    }
+
+    /// Advance a child clock by one frame, after `parent` has advanced. Returns the ticks now
+    /// due.
+    pub fn advance_from(&mut self, parent: &Clock) -> u32 {
+        self.step(parent.rate)
+    }
+
+    /// Ticks per frame in the last advance, with every ancestor's scale and pause applied.
+    pub fn rate(&self) -> f32 {
+        self.rate as f32
+    }
+
+    /// Ticks produced by the last advance.
+    pub fn due(&self) -> u32 {
+        self.due
+    }
+
+    /// Ticks produced since creation or [`reset`](Self::reset).
+    pub fn elapsed(&self) -> u32 {
+        self.elapsed
+    }
+
+    /// Advance `animation` by the ticks due, as one [`tick_n`](Animation::tick_n) step so
+    /// markers crossed on any of them are reported, and return its value.
+    pub fn drive<F: Float, A: Animation<F>>(&self, animation: &mut A) -> A::Value {
+        animation.tick_n(self.due)
+    }
+
+    /// Clear elapsed ticks and any carried fraction, keeping scale and pause.
+    pub fn reset(&mut self) {
+        self.rate = 0.0;
+        self.carry = 0.0;
+        self.due = 0;
+        self.elapsed = 0;
+    }
+
+    fn step(&mut self, parent_rate: f64) -> u32 {
+        self.rate = if self.paused {
+            0.0
+        } else {
+            parent_rate * self.time_scale as f64
+        };
+        let total = self.carry + self.rate;
+        self.due = total as u32;
+        self.carry = total - self.due as f64;
+        self.elapsed = self.elapsed.saturating_add(self.due);
+        self.due
+    }
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Clock;
+    use crate::tween::Tween;
+
+    #[test]
+    fn clock_hierarchy_scales_and_pauses() {
+        let mut world = Clock::new();
+        let mut gameplay = Clock::new().with_time_scale(0.5);
+        let mut entity = Clock::new().with_time_scale(3.0);
+        let mut ui = Clock::new();
+
+        let mut frames = [(0, 0, 0); 4];
+        for frame in &mut frames {
+            world.advance();
+            gameplay.advance_from(&world);
+            entity.advance_from(&gameplay);
+            ui.advance();
+            *frame = (gameplay.due(), entity.due(), ui.due());
+        }
+        assert_eq!(frames, [(0, 1, 1), (1, 2, 1), (0, 1, 1), (1, 2, 1)]);
+        assert_eq!(entity.rate(), 1.5);
+
+        world.pause();
+        for _ in 0..3 {
+            world.advance();
+            gameplay.advance_from(&world);
+            entity.advance_from(&gameplay);
+            ui.advance();
+        }
+        assert_eq!(
+            (gameplay.elapsed(), entity.elapsed(), ui.elapsed()),
+            (2, 6, 7)
+        );
+
+        world.resume();
+        world.advance();
+        gameplay.advance_from(&world);
+        assert_eq!(gameplay.due(), 0);
+    }
+
+    #[test]
+    fn clock_drives_animations() {
+        let mut clock = Clock::new().with_time_scale(2.0);
+        let mut tween = Tween::new(0.0f32, 10.0, 10);
+        clock.advance();
+        assert_eq!(clock.drive(&mut tween), 2.0);
+
+        clock.set_time_scale(0.0);
+        clock.advance();
+        assert_eq!(clock.drive(&mut tween), 2.0);
+
+        clock.set_time_scale(-1.0);
+        assert_eq!(clock.time_scale(), 0.0);
+        clock.set_time_scale(0.25);
+        let values: [f32; 4] = core::array::from_fn(|_| {
+            clock.advance();
+            clock.drive(&mut tween)
+        });
+        assert_eq!(values, [2.0, 2.0, 2.0, 3.0]);
+
+        clock.reset();
+        assert_eq!((clock.due(), clock.elapsed()), (0, 0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn clock_reports_markers_of_every_tick_due() {
+        use crate::keyframes::{Keyframe, Keyframes};
+        use crate::marker::Marker;
+        use crate::timeline::{AnimationTimeline, Timeline};
+        use alloc::vec;
+
+        let mut clock = Clock::new().with_time_scale(2.0);
+        let mut keyframes =
+            Keyframes::<_, f32>::new(vec![Keyframe::new(0.0f32, 0), Keyframe::new(4.0, 4)])
+                .with_marker(1, 10)
+                .with_marker(2, 20);
+        let mut timeline = Timeline::new();
+        timeline.add(0, 4);
+        timeline.add_marker(1, 10);
+        timeline.add_marker(2, 20);
+        let mut animations = AnimationTimeline::<f32, f32>::new();
+        animations.add(Tween::new(0.0, 4.0, 4));
+        animations.add_marker(1, 10);
+        animations.add_marker(2, 20);
+
+        clock.advance();
+        let both = [Marker::new(1, 10), Marker::new(2, 20)];
+        assert_eq!(clock.drive(&mut keyframes), 2.0);
+        assert_eq!(keyframes.fired_events(), both);
+        clock.drive::<f32, _>(&mut timeline);
+        assert_eq!(timeline.fired_events(), both);
+        assert_eq!(clock.drive(&mut animations)[0].1, 2.0);
+        assert_eq!(animations.fired_events(), both);
+
+        clock.pause();
+        clock.advance();
+        assert_eq!(clock.drive(&mut keyframes), 2.0);
+        assert!(keyframes.fired_events().is_empty());
+    }
+}
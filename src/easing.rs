@@ -305,8 +305,22 @@ pub fn cubic_bezier<F: Float>(t: F, x1: F, y1: F, x2: F, y2: F) -> F {
         return F::one();
     }
 
+    let s = solve_bezier_x(t, x1, x2);
+    bezier_component(s, y1, y2)
+}
+
+/// Find the curve parameter whose x coordinate is `x` on a unit cubic bezier with
+/// control x coordinates `0, x1, x2, 1`.
+pub(crate) fn solve_bezier_x<F: Float>(x: F, x1: F, x2: F) -> F {
+    if x <= F::zero() {
+        return F::zero();
+    }
+    if x >= F::one() {
+        return F::one();
+    }
+
     let epsilon = F::from_f32(1e-7);
-    let mut s = t;
+    let mut s = x;
 
     for _ in 0..8 {
         let bx = bezier_component(s, x1, x2);
@@ -314,17 +328,17 @@ pub fn cubic_bezier<F: Float>(t: F, x1: F, y1: F, x2: F, y2: F) -> F {
         if dbx.abs() < epsilon {
             break;
         }
-        s = (s - (bx - t) / dbx).clamp(F::zero(), F::one());
+        s = (s - (bx - x) / dbx).clamp(F::zero(), F::one());
     }
 
-    let residual = (bezier_component(s, x1, x2) - t).abs();
+    let residual = (bezier_component(s, x1, x2) - x).abs();
     if residual > F::from_f32(1e-5) {
         let mut lo = F::zero();
         let mut hi = F::one();
         for _ in 0..20 {
             s = (lo + hi) / F::two();
             let bx = bezier_component(s, x1, x2);
-            if bx < t {
+            if bx < x {
                 lo = s;
             } else {
                 hi = s;
@@ -332,7 +346,7 @@ pub fn cubic_bezier<F: Float>(t: F, x1: F, y1: F, x2: F, y2: F) -> F {
         }
     }
 
-    bezier_component(s, y1, y2)
+    s
 }
 
 fn bezier_component<F: Float>(s: F, p1: F, p2: F) -> F {
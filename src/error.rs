@@ -13,6 +13,20 @@ pub enum TweenError {
     },
     /// Cubic bezier control point x is outside [0, 1].
     InvalidBezierControl,
+    /// Keyframe index is past the end of the track.
+    KeyframeIndexOutOfRange { index: usize, len: usize },
+    /// Imported sampler output has the wrong number of values for its input times.
+    SamplerOutputMismatch { expected: usize, actual: usize },
+    /// Imported text is malformed at the given byte offset.
+    ParseError { position: usize },
+    /// Imported data is well-formed but missing fields or uses an unsupported layout.
+    InvalidImportData,
+    /// Retiming would move key `index` onto the previous key's tick.
+    KeyframeCollision { index: usize, tick: u32 },
+    /// No label with the given name.
+    UnknownLabel,
+    /// The id belongs to a removed entry.
+    InvalidId,
 }
 
 #[cfg(test)]
@@ -29,5 +43,15 @@ mod tests {
             prev_tick: 10,
         };
         let _ = TweenError::InvalidBezierControl;
+        let _ = TweenError::KeyframeIndexOutOfRange { index: 3, len: 2 };
+        let _ = TweenError::SamplerOutputMismatch {
+            expected: 6,
+            actual: 4,
+        };
+        let _ = TweenError::ParseError { position: 7 };
+        let _ = TweenError::InvalidImportData;
+        let _ = TweenError::KeyframeCollision { index: 1, tick: 0 };
+        let _ = TweenError::UnknownLabel;
+        let _ = TweenError::InvalidId;
     }
 }
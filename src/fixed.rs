@@ -0,0 +1,727 @@
+//! Compositions over a fixed array of children, for targets without a heap.
+//!
+//! Unlike the boxed `Sequence`, `Parallel` and `Stagger` of the `alloc` feature, every child
+//! has the same type `A` and lives inline, so
+//! constructing and ticking these never allocates. Mix animation types with an enum
+//! implementing [`Animation`], or use a [`Group`](crate::Group).
+
+use core::marker::PhantomData;
+
+use crate::animation::Animation;
+use crate::float::Float;
+use crate::loop_mode::{LoopMode, PlayDirection};
+use crate::state::TweenState;
+use crate::tween::pass_progress;
+
+/// Plays `N` animations one after another in order.
+#[derive(Clone, Debug)]
+pub struct FixedSequence<A, F: Float, const N: usize> {
+    children: [A; N],
+    current_index: usize,
+    state: TweenState,
+    loop_mode: LoopMode,
+    loops_completed: u32,
+    direction: PlayDirection,
+    _float: PhantomData<F>,
+}
+
+impl<A: Animation<F>, F: Float, const N: usize> FixedSequence<A, F, N> {
+    pub fn new(children: [A; N]) -> Self {
+        Self {
+            children,
+            current_index: 0,
+            state: if N == 0 {
+                TweenState::Idle
+            } else {
+                TweenState::Playing
+            },
+            loop_mode: LoopMode::Once,
+            loops_completed: 0,
+            direction: PlayDirection::Forward,
+            _float: PhantomData,
+        }
+    }
+
+    pub fn with_loop(mut self, mode: LoopMode) -> Self {
+        self.loop_mode = mode;
+        self
+    }
+
+    pub fn tick(&mut self) -> A::Value {
+        assert!(N > 0, "FixedSequence requires at least one animation");
+        if self.state != TweenState::Playing {
+            return self.value();
+        }
+
+        let value = self.children[self.current_index].tick();
+        if self.children[self.current_index].is_finished() {
+            let next = match self.direction {
+                PlayDirection::Forward => Some(self.current_index + 1).filter(|&next| next < N),
+                PlayDirection::Backward => self.current_index.checked_sub(1),
+            };
+            match next {
+                Some(next) => self.start_child(next),
+                None => self.on_sequence_complete(),
+            }
+        }
+        value
+    }
+
+    pub fn value(&self) -> A::Value {
+        assert!(N > 0, "FixedSequence requires at least one animation");
+        self.children[self.current_index].value()
+    }
+
+    pub fn children(&self) -> &[A; N] {
+        &self.children
+    }
+
+    pub fn children_mut(&mut self) -> &mut [A; N] {
+        &mut self.children
+    }
+
+    /// Current playback direction. Backward legs play the children last to first, each in
+    /// reverse.
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current value.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight, continuing from the current value. A finished sequence plays
+    /// back the way it came.
+    pub fn reverse(&mut self) {
+        if N == 0 {
+            return;
+        }
+        self.direction = self.direction.reversed();
+        self.children[self.current_index].reverse();
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a leg toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        if N > 0 {
+            self.direction = direction;
+            self.restart();
+        }
+    }
+
+    pub fn total_duration(&self) -> u32 {
+        self.children
+            .iter()
+            .map(|child| child.total_duration())
+            .fold(0, u32::saturating_add)
+    }
+
+    pub fn progress(&self) -> F {
+        let total = self.total_duration();
+        if total == 0 {
+            return F::one();
+        }
+        let before = match self.direction {
+            PlayDirection::Forward => &self.children[..self.current_index],
+            PlayDirection::Backward => &self.children[self.current_index + 1..],
+        };
+        let played = before
+            .iter()
+            .map(|child| child.total_duration())
+            .fold(0, u32::saturating_add);
+        let child = &self.children[self.current_index];
+        let local = child.progress().to_f32() * child.total_duration() as f32;
+        let position = played as f32 + local;
+        let position = match self.direction {
+            PlayDirection::Forward => position,
+            PlayDirection::Backward => total as f32 - position,
+        };
+        F::from_f32(position / total as f32).clamp(F::zero(), F::one())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == TweenState::Finished
+    }
+
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
+    pub fn reset(&mut self) {
+        for child in &mut self.children {
+            child.reset();
+        }
+        self.current_index = 0;
+        self.direction = PlayDirection::Forward;
+        self.state = if N == 0 {
+            TweenState::Idle
+        } else {
+            TweenState::Playing
+        };
+        self.loops_completed = 0;
+    }
+
+    fn on_sequence_complete(&mut self) {
+        match self.loop_mode {
+            LoopMode::Once => {
+                self.state = TweenState::Finished;
+            }
+            LoopMode::Count(count) => {
+                self.loops_completed += 1;
+                if count == 0 || self.loops_completed >= count {
+                    self.state = TweenState::Finished;
+                } else {
+                    self.restart();
+                }
+            }
+            LoopMode::Infinite => {
+                self.loops_completed += 1;
+                self.restart();
+            }
+            LoopMode::PingPong => {
+                self.loops_completed += 1;
+                self.direction = self.direction.reversed();
+                self.restart();
+            }
+            LoopMode::PingPongCount(count) => {
+                self.loops_completed += 1;
+                let max_legs = count.saturating_mul(2);
+                if max_legs == 0 || self.loops_completed >= max_legs {
+                    self.state = TweenState::Finished;
+                } else {
+                    self.direction = self.direction.reversed();
+                    self.restart();
+                }
+            }
+        }
+    }
+
+    fn restart(&mut self) {
+        let first = match self.direction {
+            PlayDirection::Forward => 0,
+            PlayDirection::Backward => N - 1,
+        };
+        self.start_child(first);
+    }
+
+    fn start_child(&mut self, index: usize) {
+        self.current_index = index;
+        self.children[index].rewind(self.direction);
+    }
+}
+
+/// Plays `N` animations simultaneously. Backward passes end every child together, so shorter
+/// children start late rather than finish early.
+#[derive(Clone, Debug)]
+pub struct FixedParallel<A, F: Float, const N: usize> {
+    children: [A; N],
+    elapsed: u32,
+    state: TweenState,
+    direction: PlayDirection,
+    _float: PhantomData<F>,
+}
+
+impl<A: Animation<F>, F: Float, const N: usize> FixedParallel<A, F, N> {
+    pub fn new(children: [A; N]) -> Self {
+        Self {
+            children,
+            elapsed: 0,
+            state: if N == 0 {
+                TweenState::Idle
+            } else {
+                TweenState::Playing
+            },
+            direction: PlayDirection::Forward,
+            _float: PhantomData,
+        }
+    }
+
+    pub fn tick(&mut self) -> [A::Value; N] {
+        if self.state != TweenState::Playing {
+            return self.values();
+        }
+
+        let (elapsed, total, direction) = (self.elapsed, self.total_duration(), self.direction);
+        let values = self.children.each_mut().map(|child| {
+            let start = direction.pass_start(0, child.total_duration(), total);
+            if start == elapsed {
+                child.rewind(direction);
+            }
+            if start <= elapsed {
+                child.tick()
+            } else {
+                child.value()
+            }
+        });
+        self.elapsed = self.elapsed.saturating_add(1);
+        let done = self.children.iter().all(|child| {
+            direction.pass_start(0, child.total_duration(), total) <= elapsed && child.is_finished()
+        });
+        if done {
+            self.state = TweenState::Finished;
+        }
+        values
+    }
+
+    pub fn values(&self) -> [A::Value; N] {
+        self.children.each_ref().map(|child| child.value())
+    }
+
+    pub fn children(&self) -> &[A; N] {
+        &self.children
+    }
+
+    pub fn children_mut(&mut self) -> &mut [A; N] {
+        &mut self.children
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == TweenState::Finished
+    }
+
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
+    pub fn total_duration(&self) -> u32 {
+        self.children
+            .iter()
+            .map(|child| child.total_duration())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Ticks played over the longest child's duration, falling on backward passes.
+    pub fn progress(&self) -> F {
+        pass_progress(
+            self.elapsed,
+            self.total_duration(),
+            self.state,
+            self.direction,
+        )
+    }
+
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Flip direction mid-flight. Children under way reverse in place, and ones the mirrored
+    /// pass has not reached yet wait at their starting end.
+    pub fn reverse(&mut self) {
+        let total = self.total_duration();
+        self.direction = self.direction.reversed();
+        self.elapsed = total - self.elapsed.min(total);
+        let (elapsed, direction) = (self.elapsed, self.direction);
+        for child in &mut self.children {
+            if direction.pass_start(0, child.total_duration(), total) < elapsed {
+                child.reverse();
+            }
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a pass toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+        for child in &mut self.children {
+            child.rewind(direction);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for child in &mut self.children {
+            child.reset();
+        }
+        self.elapsed = 0;
+        self.direction = PlayDirection::Forward;
+        if N > 0 {
+            self.state = TweenState::Playing;
+        }
+    }
+}
+
+/// Like [`FixedParallel`] but each animation starts at its own tick. Backward passes mirror
+/// the start ticks.
+#[derive(Clone, Debug)]
+pub struct FixedStagger<A, F: Float, const N: usize> {
+    children: [A; N],
+    starts: [u32; N],
+    elapsed: u32,
+    state: TweenState,
+    direction: PlayDirection,
+    _float: PhantomData<F>,
+}
+
+impl<A: Animation<F>, F: Float, const N: usize> FixedStagger<A, F, N> {
+    /// Each animation starts `offset` ticks after the previous one.
+    pub fn new(children: [A; N], offset: u32) -> Self {
+        let mut starts = [0; N];
+        for (index, start) in starts.iter_mut().enumerate() {
+            *start = offset.saturating_mul(index as u32);
+        }
+        Self::with_starts(children, starts)
+    }
+
+    /// Start each animation at the matching tick, e.g. from `StaggerPattern::offsets` with the
+    /// `alloc` feature.
+    pub fn with_starts(children: [A; N], starts: [u32; N]) -> Self {
+        Self {
+            children,
+            starts,
+            elapsed: 0,
+            state: if N == 0 {
+                TweenState::Idle
+            } else {
+                TweenState::Playing
+            },
+            direction: PlayDirection::Forward,
+            _float: PhantomData,
+        }
+    }
+
+    /// Start tick of each animation in a forward pass.
+    pub fn starts(&self) -> &[u32; N] {
+        &self.starts
+    }
+
+    pub fn tick(&mut self) -> [A::Value; N] {
+        if self.state != TweenState::Playing {
+            return self.values();
+        }
+
+        let (elapsed, total, direction) = (self.elapsed, self.total_duration(), self.direction);
+        let mut index = 0;
+        let values = self.children.each_mut().map(|child| {
+            let start = direction.pass_start(self.starts[index], child.total_duration(), total);
+            index += 1;
+            if start == elapsed {
+                child.rewind(direction);
+            }
+            if elapsed >= start {
+                child.tick()
+            } else {
+                child.value()
+            }
+        });
+
+        let mut children = self.children.iter().zip(&self.starts);
+        let done = children.all(|(child, &start)| {
+            direction.pass_start(start, child.total_duration(), total) <= elapsed
+                && child.is_finished()
+        });
+        if done {
+            self.state = TweenState::Finished;
+        } else {
+            self.elapsed = self.elapsed.saturating_add(1);
+        }
+
+        values
+    }
+
+    pub fn values(&self) -> [A::Value; N] {
+        self.children.each_ref().map(|child| child.value())
+    }
+
+    pub fn children(&self) -> &[A; N] {
+        &self.children
+    }
+
+    pub fn children_mut(&mut self) -> &mut [A; N] {
+        &mut self.children
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == TweenState::Finished
+    }
+
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
+    pub fn total_duration(&self) -> u32 {
+        self.children
+            .iter()
+            .zip(&self.starts)
+            .map(|(child, &start)| start.saturating_add(child.total_duration()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Falls on backward passes.
+    pub fn progress(&self) -> F {
+        pass_progress(
+            self.elapsed,
+            self.total_duration(),
+            self.state,
+            self.direction,
+        )
+    }
+
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Flip direction mid-flight. Children under way reverse in place, and ones the mirrored
+    /// pass has not reached yet wait at their starting end.
+    pub fn reverse(&mut self) {
+        let total = self.total_duration();
+        self.direction = self.direction.reversed();
+        self.elapsed = total - self.elapsed.min(total);
+        let (elapsed, direction) = (self.elapsed, self.direction);
+        for (child, &start) in self.children.iter_mut().zip(&self.starts) {
+            if direction.pass_start(start, child.total_duration(), total) < elapsed {
+                child.reverse();
+            }
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a pass toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+        for child in &mut self.children {
+            child.rewind(direction);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for child in &mut self.children {
+            child.reset();
+        }
+        self.elapsed = 0;
+        self.direction = PlayDirection::Forward;
+        if N > 0 {
+            self.state = TweenState::Playing;
+        }
+    }
+}
+
+impl<A: Animation<F>, F: Float, const N: usize> Animation<F> for FixedSequence<A, F, N> {
+    type Value = A::Value;
+
+    fn tick(&mut self) -> A::Value {
+        FixedSequence::tick(self)
+    }
+
+    fn value(&self) -> A::Value {
+        FixedSequence::value(self)
+    }
+
+    fn progress(&self) -> F {
+        FixedSequence::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        FixedSequence::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        FixedSequence::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        FixedSequence::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        FixedSequence::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        FixedSequence::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        FixedSequence::rewind(self, direction);
+    }
+}
+
+impl<A: Animation<F>, F: Float, const N: usize> Animation<F> for FixedParallel<A, F, N> {
+    type Value = [A::Value; N];
+
+    fn tick(&mut self) -> Self::Value {
+        FixedParallel::tick(self)
+    }
+
+    fn value(&self) -> Self::Value {
+        self.values()
+    }
+
+    fn progress(&self) -> F {
+        FixedParallel::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        FixedParallel::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        FixedParallel::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        FixedParallel::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        FixedParallel::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        FixedParallel::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        FixedParallel::rewind(self, direction);
+    }
+}
+
+impl<A: Animation<F>, F: Float, const N: usize> Animation<F> for FixedStagger<A, F, N> {
+    type Value = [A::Value; N];
+
+    fn tick(&mut self) -> Self::Value {
+        FixedStagger::tick(self)
+    }
+
+    fn value(&self) -> Self::Value {
+        self.values()
+    }
+
+    fn progress(&self) -> F {
+        FixedStagger::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        FixedStagger::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        FixedStagger::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        FixedStagger::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        FixedStagger::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        FixedStagger::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        FixedStagger::rewind(self, direction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FixedParallel, FixedSequence, FixedStagger};
+    use crate::loop_mode::{LoopMode, PlayDirection};
+    use crate::tween::Tween;
+
+    #[test]
+    fn fixed_sequence_ping_pong() {
+        let mut seq = FixedSequence::new([Tween::new(0.0f32, 10.0, 2), Tween::new(10.0, 20.0, 2)])
+            .with_loop(LoopMode::PingPongCount(1));
+        assert_eq!(seq.total_duration(), 4);
+        let mut values = [0.0f32; 8];
+        for value in &mut values {
+            *value = seq.tick();
+        }
+        assert_eq!(values, [5.0, 10.0, 15.0, 20.0, 15.0, 10.0, 5.0, 0.0]);
+        assert!(seq.is_finished());
+    }
+
+    #[test]
+    fn fixed_parallel_and_stagger_tick_in_place() {
+        let mut parallel =
+            FixedParallel::new([Tween::new(0.0f32, 4.0, 4), Tween::new(0.0f32, 2.0, 2)]);
+        assert_eq!(parallel.tick(), [1.0, 1.0]);
+        assert_eq!(parallel.tick(), [2.0, 2.0]);
+        assert_eq!(parallel.progress(), 0.5);
+        parallel.tick();
+        assert_eq!(parallel.tick(), [4.0, 2.0]);
+        assert!(parallel.is_finished());
+
+        let mut stagger =
+            FixedStagger::new(core::array::from_fn(|_| Tween::new(0.0f32, 2.0, 2)), 1);
+        assert_eq!(stagger.starts(), &[0, 1, 2]);
+        assert_eq!(stagger.total_duration(), 4);
+        assert_eq!(stagger.tick(), [1.0, 0.0, 0.0]);
+        assert_eq!(stagger.tick(), [2.0, 1.0, 0.0]);
+        stagger.tick();
+        assert_eq!(stagger.tick(), [2.0, 2.0, 2.0]);
+        assert!(stagger.is_finished());
+
+        stagger.reset();
+        assert_eq!(stagger.values(), [0.0; 3]);
+    }
+
+    #[test]
+    fn fixed_parallel_and_stagger_play_back_in_ping_pong() {
+        let parallel = FixedParallel::new([Tween::new(0.0f32, 4.0, 4), Tween::new(0.0f32, 2.0, 2)]);
+        let mut seq = FixedSequence::new([parallel]).with_loop(LoopMode::PingPongCount(1));
+        let mut values = [[0.0f32; 2]; 8];
+        for value in &mut values {
+            *value = seq.tick();
+        }
+        assert_eq!(
+            values,
+            [
+                [1.0, 1.0],
+                [2.0, 2.0],
+                [3.0, 2.0],
+                [4.0, 2.0],
+                [3.0, 2.0],
+                [2.0, 2.0],
+                [1.0, 1.0],
+                [0.0, 0.0],
+            ]
+        );
+        assert!(seq.is_finished());
+
+        let mut stagger =
+            FixedStagger::new(core::array::from_fn(|_| Tween::new(0.0f32, 2.0, 2)), 1);
+        for _ in 0..3 {
+            stagger.tick();
+        }
+        stagger.reverse();
+        assert_eq!(stagger.direction(), PlayDirection::Backward);
+        let mut values = [[0.0f32; 3]; 3];
+        for value in &mut values {
+            *value = stagger.tick();
+        }
+        assert_eq!(values, [[2.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+        assert!(stagger.is_finished());
+    }
+
+    #[test]
+    fn fixed_compositions_nest() {
+        let mut nested = FixedParallel::new([
+            FixedSequence::new([Tween::new(0.0f32, 1.0, 1), Tween::new(1.0, 3.0, 2)]),
+            FixedSequence::new([Tween::new(0.0f32, 3.0, 3), Tween::new(3.0, 3.0, 1)]),
+        ]);
+        assert_eq!(nested.total_duration(), 4);
+        nested.tick();
+        assert_eq!(nested.tick(), [2.0, 2.0]);
+        while !nested.is_finished() {
+            nested.tick();
+        }
+        assert_eq!(nested.values(), [3.0, 3.0]);
+    }
+}
@@ -32,6 +32,20 @@ pub trait Float:
     fn exp(self) -> Self;
     fn floor(self) -> Self;
 
+    /// Arc cosine, used by quaternion slerp. Defaults to `libm` in `f64`.
+    fn acos(self) -> Self {
+        Self::from_f64(libm::acos(self.to_f64()))
+    }
+
+    /// Defaults to converting through `f32`; override for more precision.
+    fn from_f64(v: f64) -> Self {
+        Self::from_f32(v as f32)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
     fn clamp(self, min: Self, max: Self) -> Self {
         self.max(min).min(max)
     }
@@ -78,6 +92,14 @@ impl Float for f32 {
         self
     }
 
+    fn from_f64(v: f64) -> Self {
+        v as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
     fn sqrt(self) -> Self {
         libm::sqrtf(self)
     }
@@ -121,6 +143,10 @@ impl Float for f32 {
     fn floor(self) -> Self {
         libm::floorf(self)
     }
+
+    fn acos(self) -> Self {
+        libm::acosf(self)
+    }
 }
 
 impl Float for f64 {
@@ -156,6 +182,14 @@ impl Float for f64 {
         self as f32
     }
 
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
     fn sqrt(self) -> Self {
         libm::sqrt(self)
     }
@@ -199,6 +233,10 @@ impl Float for f64 {
     fn floor(self) -> Self {
         libm::floor(self)
     }
+
+    fn acos(self) -> Self {
+        libm::acos(self)
+    }
 }
 
 #[cfg(test)]
@@ -222,6 +260,7 @@ mod tests {
         assert!((f32::from_f32(4.0).sqrt() - 2.0).abs() < EPS_F32);
         assert!(f32::zero().sin().abs() < EPS_F32);
         assert!((f32::zero().cos() - 1.0).abs() < EPS_F32);
+        assert!((f32::one().acos()).abs() < EPS_F32);
     }
 
     #[test]
@@ -0,0 +1,524 @@
+use core::marker::PhantomData;
+
+use crate::animation::Animation;
+use crate::float::Float;
+use crate::loop_mode::{LoopMode, PlayDirection};
+use crate::state::TweenState;
+
+/// A tuple of two to six [`Animation`]s, possibly of different value types, played by a
+/// [`Group`].
+///
+/// The index-based methods are the group's view of its children; indices past the end are
+/// ignored.
+pub trait AnimationTuple<F: Float> {
+    /// Tuple of the children's values.
+    type Values;
+
+    const LEN: usize;
+
+    fn values(&self) -> Self::Values;
+
+    /// Tick the children `active` selects by index and duration; the rest report their current
+    /// value.
+    fn tick_where(&mut self, active: impl FnMut(usize, u32) -> bool) -> Self::Values;
+
+    fn duration(&self, index: usize) -> u32;
+
+    fn state(&self, index: usize) -> TweenState;
+
+    fn reset(&mut self, index: usize);
+
+    fn rewind(&mut self, index: usize, direction: PlayDirection);
+
+    fn reverse(&mut self, index: usize);
+}
+
+macro_rules! impl_animation_tuple {
+    ($len:expr; $($name:ident $idx:tt),+) => {
+        impl<F: Float, $($name: Animation<F>),+> AnimationTuple<F> for ($($name,)+) {
+            type Values = ($($name::Value,)+);
+
+            const LEN: usize = $len;
+
+            fn values(&self) -> Self::Values {
+                ($(self.$idx.value(),)+)
+            }
+
+            fn tick_where(&mut self, mut active: impl FnMut(usize, u32) -> bool) -> Self::Values {
+                ($(
+                    if active($idx, self.$idx.total_duration()) {
+                        self.$idx.tick()
+                    } else {
+                        self.$idx.value()
+                    },
+                )+)
+            }
+
+            fn duration(&self, index: usize) -> u32 {
+                match index {
+                    $($idx => self.$idx.total_duration(),)+
+                    _ => 0,
+                }
+            }
+
+            fn state(&self, index: usize) -> TweenState {
+                match index {
+                    $($idx => self.$idx.state(),)+
+                    _ => TweenState::Finished,
+                }
+            }
+
+            fn reset(&mut self, index: usize) {
+                match index {
+                    $($idx => self.$idx.reset(),)+
+                    _ => {}
+                }
+            }
+
+            fn rewind(&mut self, index: usize, direction: PlayDirection) {
+                match index {
+                    $($idx => self.$idx.rewind(direction),)+
+                    _ => {}
+                }
+            }
+
+            fn reverse(&mut self, index: usize) {
+                match index {
+                    $($idx => self.$idx.reverse(),)+
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+impl_animation_tuple!(2; A 0, B 1);
+impl_animation_tuple!(3; A 0, B 1, C 2);
+impl_animation_tuple!(4; A 0, B 1, C 2, D 3);
+impl_animation_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_animation_tuple!(6; A 0, B 1, C 2, D 3, E 4, G 5);
+
+/// Plays a tuple of animations of different value types together, returning a tuple of values.
+///
+/// The group has one state and loop mode: a pass ends once every child has finished, so
+/// children should not loop forever themselves. With a stagger, child `i` starts `i * offset`
+/// ticks into the pass; backward legs mirror those start times.
+#[derive(Clone, Debug)]
+pub struct Group<C, F: Float> {
+    children: C,
+    offset: u32,
+    elapsed: u32,
+    state: TweenState,
+    loop_mode: LoopMode,
+    loops_completed: u32,
+    direction: PlayDirection,
+    _float: PhantomData<F>,
+}
+
+impl<C: AnimationTuple<F>, F: Float> Group<C, F> {
+    pub fn new(children: C) -> Self {
+        Self {
+            children,
+            offset: 0,
+            elapsed: 0,
+            state: TweenState::Playing,
+            loop_mode: LoopMode::Once,
+            loops_completed: 0,
+            direction: PlayDirection::Forward,
+            _float: PhantomData,
+        }
+    }
+
+    /// Start each child `offset` ticks after the previous one.
+    pub fn with_stagger(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_loop(mut self, mode: LoopMode) -> Self {
+        self.loop_mode = mode;
+        self
+    }
+
+    /// Advance by one tick and return every child's value.
+    pub fn tick(&mut self) -> C::Values {
+        if self.state != TweenState::Playing {
+            return self.values();
+        }
+
+        for index in 0..C::LEN {
+            if self.start(index) == self.elapsed {
+                self.children.rewind(index, self.direction);
+            }
+        }
+        let (elapsed, offset, total, direction) = (
+            self.elapsed,
+            self.offset,
+            self.total_duration(),
+            self.direction,
+        );
+        let values = self.children.tick_where(|index, duration| {
+            pass_start(index, duration, offset, total, direction) <= elapsed
+        });
+        self.elapsed = self.elapsed.saturating_add(1);
+
+        let done = (0..C::LEN).all(|index| {
+            self.start(index) < self.elapsed && self.children.state(index) == TweenState::Finished
+        });
+        if done {
+            self.on_iteration_complete();
+        }
+        values
+    }
+
+    pub fn values(&self) -> C::Values {
+        self.children.values()
+    }
+
+    pub fn children(&self) -> &C {
+        &self.children
+    }
+
+    /// Mutable access to the children, e.g. to retarget one mid-flight.
+    pub fn children_mut(&mut self) -> &mut C {
+        &mut self.children
+    }
+
+    /// Ticks from the start of a pass until the last child ends.
+    pub fn total_duration(&self) -> u32 {
+        (0..C::LEN)
+            .map(|index| self.forward_start(index) + self.children.duration(index))
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn progress(&self) -> F {
+        let total = self.total_duration();
+        if total == 0 {
+            return F::one();
+        }
+        let position = match self.direction {
+            PlayDirection::Forward => self.elapsed,
+            PlayDirection::Backward => total.saturating_sub(self.elapsed),
+        };
+        F::from_f32(position.min(total) as f32 / total as f32)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.state == TweenState::Finished
+    }
+
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
+    pub fn loops_completed(&self) -> u32 {
+        self.loops_completed
+    }
+
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current values.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight. Children under way reverse in place, finished ones play back
+    /// once the mirrored pass reaches them, and ones not yet started stay put.
+    pub fn reverse(&mut self) {
+        let total = self.total_duration();
+        let position = match self.direction {
+            PlayDirection::Forward => self.elapsed,
+            PlayDirection::Backward => total.saturating_sub(self.elapsed),
+        };
+        self.direction = self.direction.reversed();
+        self.elapsed = total - position.min(total);
+        for index in 0..C::LEN {
+            // Children whose mirrored start has passed are under way or were never reached;
+            // reversing the latter leaves them at their starting end.
+            if self.start(index) < self.elapsed {
+                self.children.reverse(index);
+            }
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a pass toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+        self.restart();
+    }
+
+    pub fn reset(&mut self) {
+        for index in 0..C::LEN {
+            self.children.reset(index);
+        }
+        self.elapsed = 0;
+        self.loops_completed = 0;
+        self.direction = PlayDirection::Forward;
+        self.state = TweenState::Playing;
+    }
+
+    /// Start tick of child `index` in a forward pass.
+    fn forward_start(&self, index: usize) -> u32 {
+        (index as u32).saturating_mul(self.offset)
+    }
+
+    /// Start tick of child `index` in the current direction's pass.
+    fn start(&self, index: usize) -> u32 {
+        pass_start(
+            index,
+            self.children.duration(index),
+            self.offset,
+            self.total_duration(),
+            self.direction,
+        )
+    }
+
+    /// Begin a pass: every child waits at its starting end.
+    fn restart(&mut self) {
+        for index in 0..C::LEN {
+            self.children.rewind(index, self.direction);
+        }
+        self.elapsed = 0;
+        self.state = TweenState::Playing;
+    }
+
+    fn on_iteration_complete(&mut self) {
+        match self.loop_mode {
+            LoopMode::Once => {
+                self.state = TweenState::Finished;
+            }
+            LoopMode::Count(count) => {
+                self.loops_completed += 1;
+                if count == 0 || self.loops_completed >= count {
+                    self.state = TweenState::Finished;
+                } else {
+                    self.restart();
+                }
+            }
+            LoopMode::Infinite => {
+                self.loops_completed += 1;
+                self.restart();
+            }
+            LoopMode::PingPong => {
+                self.loops_completed += 1;
+                self.direction = self.direction.reversed();
+                self.restart();
+            }
+            LoopMode::PingPongCount(count) => {
+                self.loops_completed += 1;
+                let max_legs = count.saturating_mul(2);
+                if max_legs == 0 || self.loops_completed >= max_legs {
+                    self.state = TweenState::Finished;
+                } else {
+                    self.direction = self.direction.reversed();
+                    self.restart();
+                }
+            }
+        }
+    }
+}
+
+/// Tick within a pass of `total` ticks at which a child of `duration` ticks starts.
+fn pass_start(
+    index: usize,
+    duration: u32,
+    offset: u32,
+    total: u32,
+    direction: PlayDirection,
+) -> u32 {
+    direction.pass_start((index as u32).saturating_mul(offset), duration, total)
+}
+
+impl<C: AnimationTuple<F>, F: Float> Animation<F> for Group<C, F> {
+    type Value = C::Values;
+
+    fn tick(&mut self) -> C::Values {
+        Group::tick(self)
+    }
+
+    fn value(&self) -> C::Values {
+        self.values()
+    }
+
+    fn progress(&self) -> F {
+        Group::progress(self)
+    }
+
+    fn total_duration(&self) -> u32 {
+        Group::total_duration(self)
+    }
+
+    fn reset(&mut self) {
+        Group::reset(self);
+    }
+
+    fn state(&self) -> TweenState {
+        Group::state(self)
+    }
+
+    fn direction(&self) -> PlayDirection {
+        Group::direction(self)
+    }
+
+    fn reverse(&mut self) {
+        Group::reverse(self);
+    }
+
+    fn rewind(&mut self, direction: PlayDirection) {
+        Group::rewind(self, direction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    use super::Group;
+    #[cfg(feature = "alloc")]
+    use crate::keyframes::{Keyframe, Keyframes};
+    #[cfg(feature = "alloc")]
+    use crate::lerp::Rgba;
+    use crate::loop_mode::{LoopMode, PlayDirection};
+    use crate::tween::Tween;
+
+    const EPS: f32 = 1e-4;
+
+    #[cfg(feature = "alloc")]
+    type Panel = Group<
+        (
+            Tween<(f32, f32), f32>,
+            Keyframes<f32, f32>,
+            Tween<Rgba<f32>, f32>,
+        ),
+        f32,
+    >;
+
+    #[cfg(feature = "alloc")]
+    fn panel() -> Panel {
+        Group::new((
+            Tween::new((0.0, 100.0), (50.0, 0.0), 10),
+            Keyframes::new(vec![
+                Keyframe::new(0.0, 0),
+                Keyframe::new(1.0, 4),
+                Keyframe::new(0.5, 6),
+            ]),
+            Tween::new(
+                Rgba::new(1.0, 0.0, 0.0, 1.0),
+                Rgba::new(0.0, 0.0, 1.0, 1.0),
+                8,
+            ),
+        ))
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn group_animates_mixed_types_together() {
+        let mut group = panel();
+        assert_eq!(group.total_duration(), 10);
+        let mut values = group.values();
+        for _ in 0..5 {
+            values = group.tick();
+        }
+        let (position, opacity, color) = values;
+        assert!((position.0 - 25.0).abs() < EPS && (position.1 - 50.0).abs() < EPS);
+        assert!((opacity - 0.75).abs() < EPS);
+        assert!((color.b - 0.625).abs() < EPS);
+        assert!((group.progress() - 0.5).abs() < EPS);
+
+        for _ in 0..4 {
+            group.tick();
+        }
+        assert!(!group.is_finished());
+        let (position, opacity, _) = group.tick();
+        assert!(group.is_finished());
+        assert!((position.0 - 50.0).abs() < EPS);
+        assert!((opacity - 0.5).abs() < EPS);
+    }
+
+    #[test]
+    fn group_stagger_offsets_children() {
+        let mut group = Group::new((
+            Tween::new(0.0f32, 4.0, 4),
+            Tween::new(0.0f32, 4.0, 4),
+            Tween::new(0.0f32, 4.0, 4),
+        ))
+        .with_stagger(2);
+        assert_eq!(group.total_duration(), 8);
+        for _ in 0..3 {
+            group.tick();
+        }
+        assert_eq!(group.values(), (3.0, 1.0, 0.0));
+        let mut ticks = 3;
+        while !group.is_finished() {
+            group.tick();
+            ticks += 1;
+        }
+        assert_eq!(ticks, 8);
+        assert_eq!(group.values(), (4.0, 4.0, 4.0));
+    }
+
+    #[test]
+    fn group_ping_pong_mirrors_staggered_pass() {
+        let mut group = Group::new((Tween::new(0.0f32, 6.0, 6), Tween::new(0.0f32, 3.0, 3)))
+            .with_stagger(4)
+            .with_loop(LoopMode::PingPongCount(1));
+        let total = group.total_duration() as usize;
+        assert_eq!(total, 7);
+
+        let mut values = [(0.0f32, 0.0f32); 15];
+        values[0] = group.values();
+        let mut len = 1;
+        while !group.is_finished() {
+            values[len] = group.tick();
+            len += 1;
+        }
+        assert_eq!(len, 2 * total + 1);
+        for offset in 0..=total {
+            let (a, b) = (values[total + offset], values[total - offset]);
+            assert!((a.0 - b.0).abs() < EPS && (a.1 - b.1).abs() < EPS);
+        }
+        assert_eq!(group.loops_completed(), 2);
+    }
+
+    #[test]
+    fn group_reverse_mid_flight_and_nesting() {
+        let mut group =
+            Group::new((Tween::new(0.0f32, 4.0, 4), Tween::new(0.0f32, 4.0, 4))).with_stagger(3);
+        for _ in 0..2 {
+            group.tick();
+        }
+        group.reverse();
+        assert_eq!(group.direction(), PlayDirection::Backward);
+        assert_eq!(group.tick(), (1.0, 0.0));
+        assert_eq!(group.tick(), (0.0, 0.0));
+        assert!(group.is_finished());
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut seq = crate::tween::Sequence::new()
+                .push(Group::new((
+                    Tween::new(0.0f32, 1.0, 2),
+                    Tween::new(0u8, 10, 2),
+                )))
+                .push(Group::new((
+                    Tween::new(1.0f32, 0.0, 2),
+                    Tween::new(10u8, 0, 2),
+                )));
+            assert_eq!(seq.total_duration(), 4);
+            for _ in 0..3 {
+                seq.tick();
+            }
+            assert_eq!(seq.value(), (0.5, 5));
+        }
+    }
+}
@@ -0,0 +1,741 @@
+//! CSS `@keyframes` and `transition` import.
+//!
+//! [`animation`] reads one property out of an `@keyframes` block and applies the
+//! `animation` shorthand or `animation-*` longhands found anywhere else in the same text.
+//! Where these list several comma-separated animations, only the first is imported.
+//! Percentages are placed on a tick grid derived from `animation-duration`. A property missing
+//! from the `0%` or `100%` keyframe holds its nearest declared value, since there is no element
+//! to take the computed value from.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::config::TweenConfig;
+use crate::easing::Easing;
+use crate::error::TweenError;
+use crate::float::Float;
+use crate::keyframes::{Interpolation, Keyframe, Keyframes};
+use crate::lerp::{Lerp, Rgba};
+use crate::loop_mode::LoopMode;
+
+/// A property track from an `@keyframes` rule with its animation timing applied.
+#[derive(Clone, Debug)]
+pub struct CssAnimation<T: Lerp<F>, F: Float> {
+    /// The `@keyframes` name.
+    pub name: String,
+    /// Keys in ticks, already configured with [`loop_mode`](Self::loop_mode).
+    pub keyframes: Keyframes<T, F>,
+    /// `animation-iteration-count` and `animation-direction` as a loop mode.
+    pub loop_mode: LoopMode,
+    /// `animation-delay` in ticks.
+    pub delay: u32,
+}
+
+/// One entry of a `transition` shorthand.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CssTransition<F: Float> {
+    pub property: String,
+    pub duration: u32,
+    pub easing: Easing<F>,
+    pub delay: u32,
+}
+
+/// Segment timing parsed from a CSS `<easing-function>`.
+#[derive(Clone, Debug, PartialEq)]
+enum Timing<F: Float> {
+    Eased(Easing<F>),
+    /// `step-end` / `steps(1, end)`: hold until the next keyframe.
+    Hold,
+}
+
+/// A keyframe selector as a fraction of the duration, its value and its segment timing.
+type Stop<T, F> = (F, T, Timing<F>);
+
+/// `@keyframes` blocks as `(name, body)`.
+type Blocks<'a> = Vec<(String, &'a str)>;
+
+/// A keyframe rule's selector list and declarations.
+type Rule<'a> = (&'a str, Vec<(&'a str, &'a str)>);
+
+/// Animation settings collected from the shorthand and longhands.
+struct Settings<F: Float> {
+    name: Option<String>,
+    duration: F,
+    delay: F,
+    timing: Timing<F>,
+    iterations: Option<F>,
+    direction: String,
+}
+
+/// Parse `property` out of the `@keyframes` rule used by the animation in `css`.
+///
+/// `parse_value` converts a declared value such as `"0.5"` or `"#fff"`; [`number`] and
+/// [`color`] cover the common cases. The `@keyframes` name is taken from `animation-name` or
+/// the shorthand, falling back to the first block. Odd iteration counts with `alternate`
+/// directions, fractional iteration counts and negative delays are not supported.
+pub fn animation<T, F>(
+    css: &str,
+    property: &str,
+    ticks_per_second: F,
+    parse_value: impl Fn(&str) -> Option<T>,
+) -> Result<CssAnimation<T, F>, TweenError>
+where
+    T: Lerp<F> + Clone,
+    F: Float,
+{
+    let css = strip_comments(css);
+    let (blocks, rest) = keyframes_blocks(&css)?;
+    let settings = animation_settings(&rest)?;
+    let (name, body) = match &settings.name {
+        Some(name) => blocks
+            .iter()
+            .find(|(block, _)| block == name)
+            .ok_or(TweenError::InvalidImportData)?,
+        None => blocks.first().ok_or(TweenError::InvalidImportData)?,
+    };
+
+    let duration = seconds_to_ticks(settings.duration, ticks_per_second)?;
+    let delay = seconds_to_ticks(settings.delay, ticks_per_second)?;
+
+    // One stop for each selector that declares the property.
+    let mut stops: Vec<Stop<T, F>> = Vec::new();
+    for (selectors, declarations) in rules(body)? {
+        let mut value = None;
+        let mut timing = settings.timing.clone();
+        for (name, raw) in declarations {
+            if name == property {
+                value = Some(parse_value(raw).ok_or(TweenError::InvalidImportData)?);
+            } else if name == "animation-timing-function" {
+                timing = parse_timing(raw)?;
+            }
+        }
+        let Some(value) = value else { continue };
+        for selector in selectors.split(',') {
+            let percent = parse_selector::<F>(selector.trim())?;
+            stops.retain(|(p, _, _)| *p != percent);
+            stops.push((percent, value.clone(), timing.clone()));
+        }
+    }
+    stops.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(core::cmp::Ordering::Equal));
+
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => (first.clone(), last.clone()),
+        _ => return Err(TweenError::InvalidImportData),
+    };
+    if first.0 > F::zero() {
+        stops.insert(0, (F::zero(), first.1, first.2));
+    }
+    if last.0 < F::one() {
+        stops.push((F::one(), last.1, last.2));
+    }
+
+    let reverse = matches!(settings.direction.as_str(), "reverse" | "alternate-reverse");
+    if reverse {
+        stops = reversed(stops)?;
+    }
+    let frames = stops
+        .into_iter()
+        .map(|(percent, value, timing)| {
+            let tick = (percent * F::from_f32(duration as f32) + F::half())
+                .floor()
+                .to_f64() as u32;
+            let frame = Keyframe::new(value, tick);
+            match timing {
+                Timing::Eased(easing) => frame.with_easing(easing),
+                Timing::Hold => frame.with_interpolation(Interpolation::Constant),
+            }
+        })
+        .collect();
+
+    let loop_mode = loop_mode(settings.iterations, &settings.direction)?;
+    Ok(CssAnimation {
+        name: name.clone(),
+        keyframes: Keyframes::try_new(frames)?.with_loop(loop_mode),
+        loop_mode,
+        delay,
+    })
+}
+
+/// Parse the `transition` shorthand out of a declaration list such as
+/// `"transition: opacity 0.3s ease-in, transform 1s 0.1s"`.
+pub fn transitions<F: Float>(
+    css: &str,
+    ticks_per_second: F,
+) -> Result<Vec<CssTransition<F>>, TweenError> {
+    let css = strip_comments(css);
+    let mut out = Vec::new();
+    for (name, value) in declarations(&css) {
+        if name != "transition" {
+            continue;
+        }
+        for item in split_top_level(value, ',') {
+            let mut times = Vec::new();
+            let mut easing = TweenConfig::ease();
+            let mut property = String::from("all");
+            for token in split_top_level(item, ' ') {
+                if let Some(seconds) = parse_time::<F>(token) {
+                    times.push(seconds);
+                } else if is_timing(token) {
+                    easing = match parse_timing(token)? {
+                        Timing::Eased(easing) => easing,
+                        Timing::Hold => return Err(TweenError::InvalidImportData),
+                    };
+                } else {
+                    property = token.to_string();
+                }
+            }
+            let duration = times.first().copied().unwrap_or(F::zero());
+            let delay = times.get(1).copied().unwrap_or(F::zero());
+            out.push(CssTransition {
+                property,
+                duration: seconds_to_ticks(duration, ticks_per_second)?,
+                easing,
+                delay: seconds_to_ticks(delay, ticks_per_second)?,
+            });
+        }
+    }
+    Ok(out)
+}
+
+/// Leading number of a value, ignoring any unit (`"10px"`, `"0.5"`, `"45deg"`, `"50%"`).
+pub fn number<F: Float>(value: &str) -> Option<F> {
+    let value = value.trim();
+    let end = value
+        .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+        .unwrap_or(value.len());
+    // Back off an exponent marker that is really the start of a unit such as `em`.
+    let mut number = &value[..end];
+    while number.ends_with(['e', 'E']) {
+        number = &number[..number.len() - 1];
+    }
+    number.parse::<f64>().ok().map(F::from_f64)
+}
+
+/// `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb(...)` or `rgba(...)` as channels from 0 to 1.
+pub fn color<F: Float>(value: &str) -> Option<Rgba<F>> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        let digit = |i: usize| {
+            hex.get(i..i + 1)
+                .and_then(|d| u8::from_str_radix(d, 16).ok())
+        };
+        let pair = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|d| u8::from_str_radix(d, 16).ok())
+        };
+        let channels = match hex.len() {
+            3 | 4 => [digit(0)?, digit(1)?, digit(2)?, digit(3).unwrap_or(15)].map(|d| d * 17),
+            6 | 8 => [pair(0)?, pair(2)?, pair(4)?, pair(6).unwrap_or(255)],
+            _ => return None,
+        };
+        let [r, g, b, a] = channels.map(|c| F::from_f32(c as f32 / 255.0));
+        return Some(Rgba::new(r, g, b, a));
+    }
+
+    let args = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+    let mut parts = args
+        .split([',', ' ', '/'])
+        .filter(|part| !part.trim().is_empty());
+    let mut channel = |scale: f32| {
+        let part = parts.next()?.trim();
+        let number = number::<F>(part)?;
+        Some(if part.ends_with('%') {
+            number / F::from_f32(100.0)
+        } else {
+            number / F::from_f32(scale)
+        })
+    };
+    let r = channel(255.0)?;
+    let g = channel(255.0)?;
+    let b = channel(255.0)?;
+    let a = channel(1.0).unwrap_or(F::one());
+    Some(Rgba::new(r, g, b, a))
+}
+
+/// Blank out `/* ... */` comments, keeping byte offsets intact for error positions.
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        let end = rest[start + 2..]
+            .find("*/")
+            .map(|e| start + e + 4)
+            .unwrap_or(rest.len());
+        out.extend(core::iter::repeat_n(' ', end - start));
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Byte offset of the `}` closing the block whose `{` is at `open`.
+fn closing_brace(css: &str, open: usize) -> Result<usize, TweenError> {
+    let mut depth = 0usize;
+    for (i, byte) in css.bytes().enumerate().skip(open) {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(TweenError::ParseError { position: open })
+}
+
+/// Split out `@keyframes` blocks as `(name, body)` and return the remaining text.
+fn keyframes_blocks(css: &str) -> Result<(Blocks<'_>, String), TweenError> {
+    let mut blocks = Vec::new();
+    let mut rest = String::new();
+    let mut pos = 0;
+    while let Some(found) = css[pos..].find("@keyframes").map(|i| pos + i) {
+        // Include vendor-prefixed at-rules such as `@-webkit-keyframes`.
+        let at = css[..found].rfind('@').filter(|&at| {
+            css[at + 1..found].ends_with('-') && !css[at..found].contains(char::is_whitespace)
+        });
+        let start = at.unwrap_or(found);
+        rest.push_str(&css[pos..start]);
+        let open = css[found..]
+            .find('{')
+            .map(|i| found + i)
+            .ok_or(TweenError::ParseError { position: found })?;
+        let name = css[found + "@keyframes".len()..open]
+            .trim()
+            .trim_matches(['"', '\''])
+            .to_string();
+        if name.is_empty() {
+            return Err(TweenError::ParseError { position: found });
+        }
+        let close = closing_brace(css, open)?;
+        blocks.push((name, &css[open + 1..close]));
+        pos = close + 1;
+    }
+    rest.push_str(&css[pos..]);
+    Ok((blocks, rest))
+}
+
+/// `selector { declarations }` rules in a keyframes body.
+fn rules(body: &str) -> Result<Vec<Rule<'_>>, TweenError> {
+    let mut out = Vec::new();
+    let mut pos = 0;
+    while let Some(open) = body[pos..].find('{').map(|i| pos + i) {
+        let close = body[open..]
+            .find('}')
+            .map(|i| open + i)
+            .ok_or(TweenError::ParseError { position: open })?;
+        out.push((body[pos..open].trim(), declarations(&body[open + 1..close])));
+        pos = close + 1;
+    }
+    if !body[pos..].trim().is_empty() {
+        return Err(TweenError::ParseError { position: pos });
+    }
+    Ok(out)
+}
+
+/// `name: value` pairs, skipping anything that is not a declaration (such as selectors).
+fn declarations(text: &str) -> Vec<(&str, &str)> {
+    text.split([';', '{', '}'])
+        .filter_map(|item| {
+            let (name, value) = item.split_once(':')?;
+            let name = name.trim();
+            let value = value.trim();
+            let is_property =
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+            is_property.then_some((name, value))
+        })
+        .collect()
+}
+
+/// Split on `separator` outside parentheses, dropping empty pieces.
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                out.push(text[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    out.push(text[start..].trim());
+    out.retain(|piece| !piece.is_empty());
+    out
+}
+
+fn animation_settings<F: Float>(css: &str) -> Result<Settings<F>, TweenError> {
+    let mut settings = Settings {
+        name: None,
+        duration: F::zero(),
+        delay: F::zero(),
+        timing: Timing::Eased(TweenConfig::ease()),
+        iterations: Some(F::one()),
+        direction: String::from("normal"),
+    };
+    for (name, value) in declarations(css) {
+        if !name.starts_with("animation") {
+            continue;
+        }
+        let value = split_top_level(value, ',')
+            .first()
+            .copied()
+            .unwrap_or(value);
+        match name {
+            "animation" => {
+                let mut times = 0;
+                for token in split_top_level(value, ' ') {
+                    if let Some(seconds) = parse_time(token) {
+                        if times == 0 {
+                            settings.duration = seconds;
+                        } else {
+                            settings.delay = seconds;
+                        }
+                        times += 1;
+                    } else if is_timing(token) {
+                        settings.timing = parse_timing(token)?;
+                    } else if let Some(iterations) = parse_iterations(token) {
+                        settings.iterations = iterations;
+                    } else if is_direction(token) {
+                        settings.direction = token.to_string();
+                    } else if !matches!(
+                        token,
+                        "none" | "forwards" | "backwards" | "both" | "running" | "paused"
+                    ) {
+                        settings.name = Some(token.to_string());
+                    }
+                }
+            }
+            "animation-name" => settings.name = Some(value.to_string()),
+            "animation-duration" => {
+                settings.duration = parse_time(value).ok_or(TweenError::InvalidImportData)?
+            }
+            "animation-delay" => {
+                settings.delay = parse_time(value).ok_or(TweenError::InvalidImportData)?
+            }
+            "animation-timing-function" => settings.timing = parse_timing(value)?,
+            "animation-iteration-count" => {
+                settings.iterations =
+                    parse_iterations(value).ok_or(TweenError::InvalidImportData)?
+            }
+            "animation-direction" if is_direction(value) => settings.direction = value.to_string(),
+            "animation-direction" => return Err(TweenError::InvalidImportData),
+            _ => {}
+        }
+    }
+    Ok(settings)
+}
+
+/// `<time>` in seconds: `2s`, `.5s` or `500ms`.
+fn parse_time<F: Float>(token: &str) -> Option<F> {
+    if let Some(ms) = token.strip_suffix("ms") {
+        return ms.parse::<f64>().ok().map(|ms| F::from_f64(ms / 1000.0));
+    }
+    token
+        .strip_suffix('s')?
+        .parse::<f64>()
+        .ok()
+        .map(F::from_f64)
+}
+
+/// `infinite` is `None`; a number is `Some(count)`.
+fn parse_iterations<F: Float>(token: &str) -> Option<Option<F>> {
+    if token == "infinite" {
+        return Some(None);
+    }
+    let count = token.parse::<f64>().ok()?;
+    (count >= 0.0).then_some(Some(F::from_f64(count)))
+}
+
+fn is_direction(token: &str) -> bool {
+    matches!(
+        token,
+        "normal" | "reverse" | "alternate" | "alternate-reverse"
+    )
+}
+
+/// Whether a shorthand token is an easing function; function-call syntax always is.
+fn is_timing(token: &str) -> bool {
+    token.contains('(') || parse_timing::<f32>(token).is_ok()
+}
+
+fn parse_timing<F: Float>(token: &str) -> Result<Timing<F>, TweenError> {
+    let eased = |easing| Ok(Timing::Eased(easing));
+    match token.trim() {
+        "linear" => eased(TweenConfig::linear()),
+        "ease" => eased(TweenConfig::ease()),
+        "ease-in" => eased(TweenConfig::ease_in()),
+        "ease-out" => eased(TweenConfig::ease_out()),
+        "ease-in-out" => eased(TweenConfig::ease_in_out()),
+        "step-end" | "steps(1)" | "steps(1, end)" | "steps(1,end)" => Ok(Timing::Hold),
+        other => {
+            let args = other
+                .strip_prefix("cubic-bezier(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .ok_or(TweenError::InvalidImportData)?;
+            let mut values = args.split(',').map(|v| v.trim().parse::<f64>().ok());
+            let mut next = || {
+                values
+                    .next()
+                    .flatten()
+                    .map(F::from_f64)
+                    .ok_or(TweenError::InvalidImportData)
+            };
+            let (x1, y1, x2, y2) = (next()?, next()?, next()?, next()?);
+            let unit = |x: F| x >= F::zero() && x <= F::one();
+            if !unit(x1) || !unit(x2) {
+                return Err(TweenError::InvalidBezierControl);
+            }
+            eased(Easing::CubicBezier { x1, y1, x2, y2 })
+        }
+    }
+}
+
+/// Keyframe selector as a fraction: `from`, `to` or a percentage.
+fn parse_selector<F: Float>(selector: &str) -> Result<F, TweenError> {
+    let percent = match selector {
+        "from" => 0.0,
+        "to" => 100.0,
+        other => other
+            .strip_suffix('%')
+            .and_then(|p| p.trim().parse::<f64>().ok())
+            .filter(|p| (0.0..=100.0).contains(p))
+            .ok_or(TweenError::InvalidImportData)?,
+    };
+    Ok(F::from_f64(percent / 100.0))
+}
+
+/// Mirror the stops in time. Each segment's timing moves to its new starting stop and its
+/// bezier is rotated half a turn, as CSS does for reversed iterations.
+fn reversed<T, F: Float>(stops: Vec<Stop<T, F>>) -> Result<Vec<Stop<T, F>>, TweenError> {
+    let timings: Vec<Timing<F>> = stops.iter().map(|(_, _, timing)| timing.clone()).collect();
+    let count = stops.len();
+    stops
+        .into_iter()
+        .rev()
+        .enumerate()
+        .map(|(i, (percent, value, _))| {
+            // New segment i runs over old segment count - 2 - i.
+            let timing = match count.checked_sub(2 + i).map(|old| &timings[old]) {
+                None => Timing::Eased(Easing::Linear),
+                Some(Timing::Hold) => return Err(TweenError::InvalidImportData),
+                Some(Timing::Eased(Easing::CubicBezier { x1, y1, x2, y2 })) => {
+                    Timing::Eased(Easing::CubicBezier {
+                        x1: F::one() - *x2,
+                        y1: F::one() - *y2,
+                        x2: F::one() - *x1,
+                        y2: F::one() - *y1,
+                    })
+                }
+                Some(Timing::Eased(easing)) => Timing::Eased(easing.clone()),
+            };
+            Ok((F::one() - percent, value, timing))
+        })
+        .collect()
+}
+
+fn loop_mode<F: Float>(iterations: Option<F>, direction: &str) -> Result<LoopMode, TweenError> {
+    let alternate = matches!(direction, "alternate" | "alternate-reverse");
+    let Some(count) = iterations else {
+        return Ok(if alternate {
+            LoopMode::PingPong
+        } else {
+            LoopMode::Infinite
+        });
+    };
+    if count.floor() != count {
+        return Err(TweenError::InvalidImportData);
+    }
+    let count = count.to_f64() as u32;
+    match (alternate, count) {
+        (false, 1) => Ok(LoopMode::Once),
+        (false, n) => Ok(LoopMode::Count(n)),
+        (true, n) if n % 2 == 0 => Ok(LoopMode::PingPongCount(n / 2)),
+        (true, 1) => Ok(LoopMode::Once),
+        (true, _) => Err(TweenError::InvalidImportData),
+    }
+}
+
+fn seconds_to_ticks<F: Float>(seconds: F, ticks_per_second: F) -> Result<u32, TweenError> {
+    if seconds < F::zero() {
+        return Err(TweenError::InvalidImportData);
+    }
+    Ok((seconds * ticks_per_second + F::half()).floor().to_f64() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{animation, color, number, transitions, CssTransition};
+    use crate::config::TweenConfig;
+    use crate::error::TweenError;
+    use crate::keyframes::Interpolation;
+    use crate::loop_mode::LoopMode;
+
+    const EPS: f32 = 1e-3;
+
+    const FADE: &str = "
+        /* fade in, hold, then drop */
+        @keyframes fade {
+            from { opacity: 0; animation-timing-function: linear; }
+            50%, 75% { opacity: 1; animation-timing-function: step-end; }
+            to { opacity: 0.2; }
+        }
+        .box { animation: fade 2s ease-in 0.5s infinite; }
+    ";
+
+    #[test]
+    fn css_keyframes_with_shorthand() {
+        let anim = animation(FADE, "opacity", 60.0f32, number::<f32>).unwrap();
+        assert_eq!(anim.name, "fade");
+        assert_eq!(anim.delay, 30);
+        assert_eq!(anim.loop_mode, LoopMode::Infinite);
+        let keys = anim.keyframes.keys();
+        let ticks: alloc::vec::Vec<u32> = keys.iter().map(|k| k.tick).collect();
+        assert_eq!(ticks, [0, 60, 90, 120]);
+        assert_eq!(keys[1].interpolation, Interpolation::Constant);
+        assert!((anim.keyframes.sample(30) - 0.5).abs() < EPS);
+        assert!((anim.keyframes.sample(100) - 1.0).abs() < EPS);
+        assert!((anim.keyframes.sample(120) - 0.2).abs() < EPS);
+    }
+
+    #[test]
+    fn css_longhands_and_implicit_ends() {
+        let css = "
+            @keyframes slide { 25% { left: 10px } 50% { left: 30px } }
+            @keyframes other { from { left: 0 } to { left: 1px } }
+            animation-name: slide;
+            animation-duration: 400ms;
+            animation-iteration-count: 4;
+            animation-direction: alternate;
+        ";
+        let anim = animation(css, "left", 10.0f32, number::<f32>).unwrap();
+        assert_eq!(anim.loop_mode, LoopMode::PingPongCount(2));
+        assert_eq!(anim.keyframes.total_duration(), 4);
+        assert!((anim.keyframes.sample(0) - 10.0).abs() < EPS);
+        assert!((anim.keyframes.sample(4) - 30.0).abs() < EPS);
+    }
+
+    #[test]
+    fn css_reverse_mirrors_keys_and_easing() {
+        let css = "
+            @keyframes grow { from { width: 0; } to { width: 100px; } }
+            animation: grow 1s cubic-bezier(0.1, 0.2, 0.3, 0.4) reverse;
+        ";
+        let anim = animation(css, "width", 10.0f32, number::<f32>).unwrap();
+        assert!((anim.keyframes.sample(0) - 100.0).abs() < EPS);
+        assert!((anim.keyframes.sample(10) - 0.0).abs() < EPS);
+        let easing = &anim.keyframes.keys()[0].easing;
+        assert_eq!(
+            *easing,
+            crate::easing::Easing::CubicBezier {
+                x1: 0.7,
+                y1: 0.6,
+                x2: 0.9,
+                y2: 0.8
+            }
+        );
+    }
+
+    #[test]
+    fn css_colour_values() {
+        let css = "@keyframes c { from { color: #f00 } to { color: rgba(0, 0, 255, 0.5) } }
+                   animation: c 1s linear";
+        let anim = animation(css, "color", 10.0f32, color::<f32>).unwrap();
+        let start = anim.keyframes.sample(0);
+        assert!((start.r - 1.0).abs() < EPS && (start.a - 1.0).abs() < EPS);
+        let end = anim.keyframes.sample(10);
+        assert!((end.b - 1.0).abs() < EPS && (end.a - 0.5).abs() < EPS);
+        assert_eq!(color::<f32>("#00ff0080").map(|c| c.g), Some(1.0));
+        assert_eq!(number::<f32>("1.5em"), Some(1.5));
+        assert_eq!(number::<f32>("-2e1px"), Some(-20.0));
+    }
+
+    #[test]
+    fn css_transition_shorthand() {
+        let parsed =
+            transitions::<f32>("transition: opacity 0.3s ease-in, left 1s 100ms", 60.0).unwrap();
+        assert_eq!(
+            parsed[0],
+            CssTransition {
+                property: "opacity".into(),
+                duration: 18,
+                easing: TweenConfig::ease_in(),
+                delay: 0,
+            }
+        );
+        assert_eq!(parsed[1].duration, 60);
+        assert_eq!(parsed[1].delay, 6);
+        assert_eq!(parsed[1].easing, TweenConfig::ease());
+    }
+
+    #[test]
+    fn css_errors() {
+        assert!(matches!(
+            animation("@keyframes a { from { x: 0 }", "x", 60.0f32, number::<f32>),
+            Err(TweenError::ParseError { .. })
+        ));
+        assert_eq!(
+            animation(
+                "@keyframes a { from { x: 0 } } animation: b 1s",
+                "x",
+                60.0f32,
+                number::<f32>
+            )
+            .unwrap_err(),
+            TweenError::InvalidImportData
+        );
+        assert_eq!(
+            animation(
+                "@keyframes a { from { x: 0 } to { x: 1 } } animation: a 1s 3 alternate",
+                "x",
+                60.0f32,
+                number::<f32>
+            )
+            .unwrap_err(),
+            TweenError::InvalidImportData
+        );
+        assert_eq!(
+            animation(
+                "@keyframes a { to { x: 1 } } animation: a 1s cubic-bezier(2, 0, 0, 1)",
+                "x",
+                60.0f32,
+                number::<f32>
+            )
+            .unwrap_err(),
+            TweenError::InvalidBezierControl
+        );
+    }
+
+    #[test]
+    fn css_animation_lists_use_the_first_entry() {
+        let css = "
+            @keyframes a { from { x: 0 } to { x: 1 } }
+            @keyframes b { from { x: 5 } to { x: 6 } }
+            animation: a 1s linear, b 2s 0.5s;
+        ";
+        let anim = animation(css, "x", 10.0f32, number::<f32>).unwrap();
+        assert_eq!((anim.name.as_str(), anim.delay), ("a", 0));
+        assert_eq!(anim.keyframes.total_duration(), 10);
+
+        let css = "
+            @keyframes b { from { x: 5 } to { x: 6 } }
+            animation-name: b, a;
+            animation-duration: 2s, 1s;
+        ";
+        let anim = animation(css, "x", 10.0f32, number::<f32>).unwrap();
+        assert_eq!(
+            (anim.name.as_str(), anim.keyframes.total_duration()),
+            ("b", 20)
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! glTF 2.0 animation sampler import.
+//!
+//! A sampler pairs an input accessor of key times in seconds with an output accessor of values.
+//! Both are passed as flat `f32` slices, as read from the buffer views.
+
+use alloc::vec::Vec;
+
+use crate::error::TweenError;
+use crate::float::Float;
+use crate::keyframes::{Interpolation, Keyframe, Keyframes};
+use crate::lerp::{slerp_xyzw, Lerp};
+use crate::spline::Spline;
+use crate::vector::VectorSpace;
+
+/// Sampler interpolation as named in the glTF `interpolation` property.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SamplerInterpolation {
+    #[default]
+    Linear,
+    Step,
+    /// Output holds an in-tangent, value and out-tangent per key, tangents in units per second.
+    CubicSpline,
+}
+
+impl SamplerInterpolation {
+    /// Parse `"LINEAR"`, `"STEP"` or `"CUBICSPLINE"`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "LINEAR" => Some(Self::Linear),
+            "STEP" => Some(Self::Step),
+            "CUBICSPLINE" => Some(Self::CubicSpline),
+            _ => None,
+        }
+    }
+}
+
+/// Raw data of one glTF animation sampler.
+#[derive(Copy, Clone, Debug)]
+pub struct Sampler<'a> {
+    /// Key times in seconds.
+    pub input: &'a [f32],
+    /// Flattened output values.
+    pub output: &'a [f32],
+    pub interpolation: SamplerInterpolation,
+}
+
+impl<'a> Sampler<'a> {
+    pub fn new(input: &'a [f32], output: &'a [f32], interpolation: SamplerInterpolation) -> Self {
+        Self {
+            input,
+            output,
+            interpolation,
+        }
+    }
+}
+
+/// Unit quaternion `[x, y, z, w]` that interpolates by slerp.
+///
+/// Cubic-spline rotation tracks are not unit length between keys; glTF requires the sampled
+/// value to be normalized, which [`normalized`](Self::normalized) does.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rotation<F: Float>(pub [F; 4]);
+
+impl<F: Float> Rotation<F> {
+    pub fn normalized(&self) -> [F; 4] {
+        let len = self.0.length();
+        if len == F::zero() {
+            return [F::zero(), F::zero(), F::zero(), F::one()];
+        }
+        self.0.map(|c| c / len)
+    }
+}
+
+impl<F: Float> Lerp<F> for Rotation<F> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        Rotation(slerp_xyzw(self.0, other.0, t))
+    }
+}
+
+impl<F: Float> VectorSpace<F> for Rotation<F> {
+    fn zero() -> Self {
+        Rotation(<[F; 4]>::zero())
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Rotation(self.0.plus(&other.0))
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        Rotation(self.0.minus(&other.0))
+    }
+
+    fn scaled(&self, factor: F) -> Self {
+        Rotation(self.0.scaled(factor))
+    }
+
+    fn dot(&self, other: &Self) -> F {
+        self.0.dot(&other.0)
+    }
+}
+
+/// Translation track (`VEC3` output).
+pub fn translations<F: Float>(
+    sampler: &Sampler<'_>,
+    ticks_per_second: F,
+) -> Result<Keyframes<[F; 3], F>, TweenError> {
+    track(sampler, 3, ticks_per_second, |c| {
+        [F::from_f32(c[0]), F::from_f32(c[1]), F::from_f32(c[2])]
+    })
+}
+
+/// Scale track (`VEC3` output).
+pub fn scales<F: Float>(
+    sampler: &Sampler<'_>,
+    ticks_per_second: F,
+) -> Result<Keyframes<[F; 3], F>, TweenError> {
+    translations(sampler, ticks_per_second)
+}
+
+/// Rotation track (`VEC4` quaternion output).
+pub fn rotations<F: Float>(
+    sampler: &Sampler<'_>,
+    ticks_per_second: F,
+) -> Result<Keyframes<Rotation<F>, F>, TweenError> {
+    track(sampler, 4, ticks_per_second, |c| {
+        Rotation([
+            F::from_f32(c[0]),
+            F::from_f32(c[1]),
+            F::from_f32(c[2]),
+            F::from_f32(c[3]),
+        ])
+    })
+}
+
+/// One track per morph target from a `weights` sampler driving `targets` targets.
+pub fn weights<F: Float>(
+    sampler: &Sampler<'_>,
+    targets: usize,
+    ticks_per_second: F,
+) -> Result<Vec<Keyframes<F, F>>, TweenError> {
+    (0..targets)
+        .map(|target| {
+            track(sampler, targets, ticks_per_second, |c| {
+                F::from_f32(c[target])
+            })
+        })
+        .collect()
+}
+
+/// Build a track from a sampler whose output elements have `components` floats each.
+fn track<T, F>(
+    sampler: &Sampler<'_>,
+    components: usize,
+    ticks_per_second: F,
+    read: impl Fn(&[f32]) -> T,
+) -> Result<Keyframes<T, F>, TweenError>
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let per_key = match sampler.interpolation {
+        SamplerInterpolation::CubicSpline => 3,
+        _ => 1,
+    };
+    let expected = sampler.input.len() * per_key * components;
+    if sampler.output.len() != expected {
+        return Err(TweenError::SamplerOutputMismatch {
+            expected,
+            actual: sampler.output.len(),
+        });
+    }
+
+    // Tangents are per second; keyframe splines take them per tick.
+    let per_tick = F::one() / ticks_per_second;
+    let element = |index: usize| read(&sampler.output[index * components..][..components]);
+    let frames = sampler
+        .input
+        .iter()
+        .enumerate()
+        .map(|(i, &seconds)| {
+            let tick = seconds_to_tick(seconds, ticks_per_second);
+            match sampler.interpolation {
+                SamplerInterpolation::Linear => Keyframe::new(element(i), tick),
+                SamplerInterpolation::Step => {
+                    Keyframe::new(element(i), tick).with_interpolation(Interpolation::Constant)
+                }
+                SamplerInterpolation::CubicSpline => Keyframe::new(element(3 * i + 1), tick)
+                    .with_spline(Spline::Hermite {
+                        in_tangent: element(3 * i).scaled(per_tick),
+                        out_tangent: element(3 * i + 2).scaled(per_tick),
+                    }),
+            }
+        })
+        .collect();
+    Keyframes::try_new(frames)
+}
+
+/// Nearest tick to a time in seconds; negative times clamp to zero.
+fn seconds_to_tick<F: Float>(seconds: f32, ticks_per_second: F) -> u32 {
+    let ticks = F::from_f32(seconds.max(0.0)) * ticks_per_second + F::half();
+    ticks.floor().to_f64() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rotations, translations, weights, Sampler, SamplerInterpolation};
+    use crate::error::TweenError;
+
+    const EPS: f32 = 1e-4;
+
+    #[test]
+    fn gltf_linear_translation() {
+        let input = [0.0, 0.5, 1.0];
+        let output = [0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 1.0, 2.0, 5.0];
+        let sampler = Sampler::new(&input, &output, SamplerInterpolation::Linear);
+        let track = translations(&sampler, 60.0f32).unwrap();
+        assert_eq!(track.total_duration(), 60);
+        let v = track.sample(15);
+        assert!((v[0] - 0.5).abs() < EPS && (v[2] - 1.5).abs() < EPS);
+        assert!((track.sample(45)[2] - 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn gltf_step_holds() {
+        let input = [0.0, 1.0];
+        let output = [1.0, 0.0, 0.0, 2.0, 0.0, 0.0];
+        let sampler = Sampler::new(&input, &output, SamplerInterpolation::Step);
+        let track = translations(&sampler, 30.0f32).unwrap();
+        assert_eq!(track.sample(29)[0], 1.0);
+        assert_eq!(track.sample(30)[0], 2.0);
+    }
+
+    #[test]
+    fn gltf_rotation_slerps() {
+        let s = core::f32::consts::FRAC_1_SQRT_2;
+        let input = [0.0, 1.0];
+        let output = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, s, s];
+        let sampler = Sampler::new(&input, &output, SamplerInterpolation::Linear);
+        let track = rotations(&sampler, 10.0f32).unwrap();
+        let q = track.sample(5).normalized();
+        let half = (core::f32::consts::PI / 8.0).sin();
+        assert!((q[2] - half).abs() < EPS);
+        assert!((q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3] - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn gltf_cubic_spline_tangents() {
+        // Scalar weights: keys 0 -> 1 over one second with out/in tangents of 0 and 3 per second.
+        // Hermite at s = 0.5: 0.5 * p1 + 0.125 * m0 - 0.125 * m1 = 0.5 - 0.375.
+        let input = [0.0, 1.0];
+        let output = [0.0, 0.0, 0.0, 3.0, 1.0, 0.0];
+        let sampler = Sampler::new(&input, &output, SamplerInterpolation::CubicSpline);
+        let tracks = weights(&sampler, 1, 100.0f32).unwrap();
+        assert_eq!(tracks.len(), 1);
+        assert!((tracks[0].sample(50) - 0.125).abs() < EPS);
+        assert!((tracks[0].sample(100) - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn gltf_morph_weights_split_per_target() {
+        let input = [0.0, 1.0];
+        let output = [0.0, 1.0, 1.0, 0.0];
+        let sampler = Sampler::new(&input, &output, SamplerInterpolation::Linear);
+        let tracks = weights(&sampler, 2, 4.0f32).unwrap();
+        assert!((tracks[0].sample(1) - 0.25).abs() < EPS);
+        assert!((tracks[1].sample(1) - 0.75).abs() < EPS);
+    }
+
+    #[test]
+    fn gltf_rejects_bad_samplers() {
+        let sampler = Sampler::new(&[0.0, 1.0], &[0.0; 5], SamplerInterpolation::Linear);
+        assert_eq!(
+            translations(&sampler, 60.0f32).unwrap_err(),
+            TweenError::SamplerOutputMismatch {
+                expected: 6,
+                actual: 5
+            }
+        );
+        let sampler = Sampler::new(&[1.0, 0.0], &[0.0; 6], SamplerInterpolation::Linear);
+        assert!(matches!(
+            translations(&sampler, 60.0f32),
+            Err(TweenError::KeyframeOutOfOrder { .. })
+        ));
+        assert_eq!(
+            SamplerInterpolation::from_name("CUBICSPLINE"),
+            Some(SamplerInterpolation::CubicSpline)
+        );
+        assert_eq!(SamplerInterpolation::from_name("cubic"), None);
+    }
+}
@@ -0,0 +1,369 @@
+//! Lottie (Bodymovin) animated property import.
+//!
+//! Parses the JSON of a single animated property (`{"a": 1, "k": [...]}`) or of a layer's
+//! transform (`ks`) object. Key times are Lottie frames; `ticks_per_frame` converts them to
+//! ticks. Segment easing comes from each key's `o`/`i` tangents as [`Easing::CubicBezier`],
+//! hold keys (`"h": 1`) become [`Interpolation::Constant`], and position spatial tangents
+//! (`to`/`ti`) become Hermite [`Spline`] segments. Motion along a curved path follows the
+//! curve parameter rather than arc length, so speed along strongly curved paths can differ
+//! slightly from After Effects.
+
+use alloc::vec::Vec;
+
+use crate::easing::Easing;
+use crate::error::TweenError;
+use crate::float::Float;
+use crate::import::json::{self, Value};
+use crate::keyframes::{Interpolation, Keyframe, Keyframes};
+use crate::lerp::{Lerp, Rgba};
+use crate::spline::Spline;
+use crate::vector::VectorSpace;
+
+/// Animated 2D layer transform, in Lottie units.
+#[derive(Clone, Debug)]
+pub struct Transform<F: Float> {
+    /// Anchor point in pixels.
+    pub anchor: Keyframes<[F; 2], F>,
+    /// Position in pixels.
+    pub position: Keyframes<[F; 2], F>,
+    /// Scale in percent.
+    pub scale: Keyframes<[F; 2], F>,
+    /// Rotation in degrees.
+    pub rotation: Keyframes<F, F>,
+    /// Opacity from 0 to 100.
+    pub opacity: Keyframes<F, F>,
+}
+
+/// Scalar property such as rotation or opacity.
+pub fn scalar<F: Float>(json: &str, ticks_per_frame: F) -> Result<Keyframes<F, F>, TweenError> {
+    scalar_property(&json::parse(json)?, ticks_per_frame)
+}
+
+/// Multi-dimensional property such as position, anchor or scale.
+///
+/// Extra components are dropped and missing ones are zero, so a 3D position can be read as
+/// `[F; 2]`.
+pub fn vector<F: Float, const N: usize>(
+    json: &str,
+    ticks_per_frame: F,
+) -> Result<Keyframes<[F; N], F>, TweenError> {
+    vector_property(&json::parse(json)?, ticks_per_frame)
+}
+
+/// Colour property with components from 0 to 1; alpha defaults to 1.
+pub fn color<F: Float>(
+    json: &str,
+    ticks_per_frame: F,
+) -> Result<Keyframes<Rgba<F>, F>, TweenError> {
+    let property = json::parse(json)?;
+    let frames = frames(&property, ticks_per_frame, read_color)?;
+    Keyframes::try_new(frames)
+}
+
+/// Layer transform (`ks`) object. Missing properties hold their Lottie defaults.
+pub fn transform<F: Float>(json: &str, ticks_per_frame: F) -> Result<Transform<F>, TweenError> {
+    let ks = json::parse(json)?;
+    let vec2 = |key: &str, default: F| match ks.get(key) {
+        Some(property) => vector_property(property, ticks_per_frame),
+        None => Ok(Keyframes::new(alloc::vec![Keyframe::new([default; 2], 0)])),
+    };
+    let scalar = |key: &str, default: F| match ks.get(key) {
+        Some(property) => scalar_property(property, ticks_per_frame),
+        None => Ok(Keyframes::new(alloc::vec![Keyframe::new(default, 0)])),
+    };
+    if ks.get("p").and_then(|p| p.get("s")).and_then(Value::as_f64) == Some(1.0) {
+        // Separated x/y position dimensions are keyed independently.
+        return Err(TweenError::InvalidImportData);
+    }
+    let rotation = if ks.get("r").is_some() { "r" } else { "rz" };
+    Ok(Transform {
+        anchor: vec2("a", F::zero())?,
+        position: vec2("p", F::zero())?,
+        scale: vec2("s", F::from_f32(100.0))?,
+        rotation: scalar(rotation, F::zero())?,
+        opacity: scalar("o", F::from_f32(100.0))?,
+    })
+}
+
+fn scalar_property<F: Float>(
+    property: &Value,
+    ticks_per_frame: F,
+) -> Result<Keyframes<F, F>, TweenError> {
+    Keyframes::try_new(frames(property, ticks_per_frame, read_scalar)?)
+}
+
+fn vector_property<F: Float, const N: usize>(
+    property: &Value,
+    ticks_per_frame: F,
+) -> Result<Keyframes<[F; N], F>, TweenError> {
+    let mut frames = frames(property, ticks_per_frame, read_vector::<F, N>)?;
+    if let Some(keys) = animated_keys(property) {
+        apply_spatial_tangents(&mut frames, keys);
+    }
+    Keyframes::try_new(frames)
+}
+
+/// The keyframe objects of an animated property, or `None` for a static one.
+fn animated_keys(property: &Value) -> Option<&[Value]> {
+    let keys = property.get("k")?.as_array()?;
+    match keys.first() {
+        Some(Value::Object(_)) => Some(keys),
+        _ => None,
+    }
+}
+
+fn frames<T, F>(
+    property: &Value,
+    ticks_per_frame: F,
+    read: fn(&Value) -> Option<T>,
+) -> Result<Vec<Keyframe<T, F>>, TweenError>
+where
+    T: Lerp<F>,
+    F: Float,
+{
+    let value = property.get("k").ok_or(TweenError::InvalidImportData)?;
+    let Some(keys) = animated_keys(property) else {
+        let value = read(value).ok_or(TweenError::InvalidImportData)?;
+        return Ok(alloc::vec![Keyframe::new(value, 0)]);
+    };
+
+    let mut frames: Vec<Keyframe<T, F>> = Vec::with_capacity(keys.len());
+    for (i, key) in keys.iter().enumerate() {
+        let frame = key
+            .get("t")
+            .and_then(Value::as_f64)
+            .ok_or(TweenError::InvalidImportData)?;
+        // Older exports end segments with `e` and leave the final key without `s`.
+        let value = match key.get("s") {
+            Some(start) => read(start),
+            None => i
+                .checked_sub(1)
+                .and_then(|prev| keys[prev].get("e"))
+                .and_then(read),
+        }
+        .ok_or(TweenError::InvalidImportData)?;
+
+        let tick = frame_to_tick(frame, ticks_per_frame);
+        let mut keyframe = Keyframe::new(value, tick);
+        if key.get("h").and_then(Value::as_f64) == Some(1.0) {
+            keyframe = keyframe.with_interpolation(Interpolation::Constant);
+        } else if let (Some(out), Some(into)) = (key.get("o"), key.get("i")) {
+            keyframe = keyframe.with_easing(bezier_easing(out, into)?);
+        }
+        frames.push(keyframe);
+    }
+    Ok(frames)
+}
+
+/// Segment easing from a key's out tangent (`o`) and the next key's in tangent (`i`).
+fn bezier_easing<F: Float>(out: &Value, into: &Value) -> Result<Easing<F>, TweenError> {
+    // Per-dimension easing stores one value per component; the first drives the segment.
+    let coord = |handle: &Value, axis: &str| {
+        handle
+            .get(axis)
+            .and_then(|v| v.as_array().and_then(|a| a.first()).unwrap_or(v).as_f64())
+            .map(F::from_f64)
+            .ok_or(TweenError::InvalidImportData)
+    };
+    Ok(Easing::CubicBezier {
+        x1: coord(out, "x")?.clamp(F::zero(), F::one()),
+        y1: coord(out, "y")?,
+        x2: coord(into, "x")?.clamp(F::zero(), F::one()),
+        y2: coord(into, "y")?,
+    })
+}
+
+/// Turn `to`/`ti` spatial tangents into Hermite segments. Straight segments keep chord
+/// tangents so they still move at constant speed.
+fn apply_spatial_tangents<F: Float, const N: usize>(
+    frames: &mut [Keyframe<[F; N], F>],
+    keys: &[Value],
+) {
+    let tangent = |key: &Value, name: &str| key.get(name).and_then(read_vector::<F, N>);
+    if !keys
+        .iter()
+        .any(|key| tangent(key, "to").is_some() || tangent(key, "ti").is_some())
+    {
+        return;
+    }
+
+    let count = frames.len();
+    let mut tangents = alloc::vec![(<[F; N]>::zero(), <[F; N]>::zero()); count];
+    for i in 0..count.saturating_sub(1) {
+        let dt = frames[i + 1].tick.saturating_sub(frames[i].tick);
+        if dt == 0 {
+            continue;
+        }
+        let per_tick = F::one() / F::from_f32(dt as f32);
+        let out = tangent(&keys[i], "to").unwrap_or_else(<[F; N]>::zero);
+        let into = tangent(&keys[i], "ti").unwrap_or_else(<[F; N]>::zero);
+        let (m0, m1) = if out.length() == F::zero() && into.length() == F::zero() {
+            let chord = frames[i + 1].value.minus(&frames[i].value).scaled(per_tick);
+            (chord, chord)
+        } else {
+            let three = F::from_f32(3.0) * per_tick;
+            (out.scaled(three), into.scaled(-three))
+        };
+        tangents[i].1 = m0;
+        tangents[i + 1].0 = m1;
+    }
+
+    for (frame, (in_tangent, out_tangent)) in frames.iter_mut().zip(tangents) {
+        *frame = frame.clone().with_spline(Spline::Hermite {
+            in_tangent,
+            out_tangent,
+        });
+    }
+}
+
+fn frame_to_tick<F: Float>(frame: f64, ticks_per_frame: F) -> u32 {
+    let ticks = F::from_f64(frame.max(0.0)) * ticks_per_frame + F::half();
+    ticks.floor().to_f64() as u32
+}
+
+fn read_scalar<F: Float>(value: &Value) -> Option<F> {
+    match value {
+        Value::Array(items) => items.first()?.as_f64(),
+        other => other.as_f64(),
+    }
+    .map(F::from_f64)
+}
+
+fn read_vector<F: Float, const N: usize>(value: &Value) -> Option<[F; N]> {
+    match value {
+        Value::Array(items) => {
+            let mut out = [F::zero(); N];
+            for (slot, item) in out.iter_mut().zip(items) {
+                *slot = F::from_f64(item.as_f64()?);
+            }
+            Some(out)
+        }
+        other => {
+            let mut out = [F::zero(); N];
+            *out.first_mut()? = F::from_f64(other.as_f64()?);
+            Some(out)
+        }
+    }
+}
+
+fn read_color<F: Float>(value: &Value) -> Option<Rgba<F>> {
+    let items = value.as_array()?;
+    let channel = |i: usize| items.get(i).and_then(Value::as_f64).map(F::from_f64);
+    Some(Rgba::new(
+        channel(0)?,
+        channel(1)?,
+        channel(2)?,
+        channel(3).unwrap_or(F::one()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color, scalar, transform, vector};
+    use crate::error::TweenError;
+    use crate::keyframes::Interpolation;
+
+    const EPS: f32 = 1e-3;
+
+    #[test]
+    fn lottie_static_property() {
+        let track = scalar::<f32>(r#"{"a": 0, "k": 45}"#, 1.0).unwrap();
+        assert_eq!(track.sample(10), 45.0);
+        let track = vector::<f32, 2>(r#"{"a": 0, "k": [10, 20, 0]}"#, 1.0).unwrap();
+        assert_eq!(track.sample(0), [10.0, 20.0]);
+    }
+
+    #[test]
+    fn lottie_bezier_easing() {
+        let json = r#"{"a": 1, "k": [
+            {"t": 0, "s": [0], "o": {"x": [0.42], "y": [0]}, "i": {"x": [0.58], "y": [1]}},
+            {"t": 30, "s": [100]}
+        ]}"#;
+        let track = scalar::<f32>(json, 2.0).unwrap();
+        assert_eq!(track.total_duration(), 60);
+        assert!((track.sample(30) - 50.0).abs() < EPS);
+        assert!(track.sample(12) < 20.0);
+        assert!((track.sample(60) - 100.0).abs() < EPS);
+    }
+
+    #[test]
+    fn lottie_hold_and_legacy_end_values() {
+        let json = r#"{"a": 1, "k": [
+            {"t": 0, "s": [0], "e": [10], "h": 1},
+            {"t": 10, "s": [10], "e": [20], "o": {"x": 0, "y": 0}, "i": {"x": 1, "y": 1}},
+            {"t": 20}
+        ]}"#;
+        let track = scalar::<f32>(json, 1.0).unwrap();
+        assert_eq!(track.keys()[0].interpolation, Interpolation::Constant);
+        assert_eq!(track.sample(9), 0.0);
+        assert!((track.sample(15) - 15.0).abs() < EPS);
+        assert!((track.sample(20) - 20.0).abs() < EPS);
+    }
+
+    #[test]
+    fn lottie_spatial_tangents_curve_the_path() {
+        // A quarter-circle-like arc from (0, 0) to (100, 100) bulging through (100, 0).
+        let json = r#"{"a": 1, "k": [
+            {"t": 0, "s": [0, 0], "to": [55, 0], "ti": [0, -55],
+             "o": {"x": 0, "y": 0}, "i": {"x": 1, "y": 1}},
+            {"t": 10, "s": [100, 100]}
+        ]}"#;
+        let track = vector::<f32, 2>(json, 1.0).unwrap();
+        let mid = track.sample(5);
+        // Bezier midpoint: (0 + 3 * 55 + 3 * 100 + 100) / 8 = 70.625 and its mirror.
+        assert!((mid[0] - 70.625).abs() < EPS);
+        assert!((mid[1] - 29.375).abs() < EPS);
+
+        let straight = r#"{"a": 1, "k": [
+            {"t": 0, "s": [0, 0], "to": [0, 0], "ti": [0, 0],
+             "o": {"x": 0, "y": 0}, "i": {"x": 1, "y": 1}},
+            {"t": 10, "s": [100, 0]}
+        ]}"#;
+        let track = vector::<f32, 2>(straight, 1.0).unwrap();
+        assert!((track.sample(3)[0] - 30.0).abs() < EPS);
+    }
+
+    #[test]
+    fn lottie_colour_and_transform() {
+        let track = color::<f32>(
+            r#"{"a": 1, "k": [
+                {"t": 0, "s": [1, 0, 0, 1], "o": {"x": 0, "y": 0}, "i": {"x": 1, "y": 1}},
+                {"t": 10, "s": [0, 0, 1, 1]}
+            ]}"#,
+            1.0,
+        )
+        .unwrap();
+        let mid = track.sample(5);
+        assert!((mid.r - 0.5).abs() < EPS && (mid.b - 0.5).abs() < EPS);
+
+        let ks = transform::<f32>(
+            r#"{"o": {"a": 1, "k": [
+                    {"t": 0, "s": [0], "o": {"x": 0, "y": 0}, "i": {"x": 1, "y": 1}},
+                    {"t": 10, "s": [100]}]},
+                "r": {"a": 0, "k": 90}}"#,
+            1.0,
+        )
+        .unwrap();
+        assert!((ks.opacity.sample(5) - 50.0).abs() < EPS);
+        assert_eq!(ks.rotation.sample(0), 90.0);
+        assert_eq!(ks.scale.sample(0), [100.0, 100.0]);
+        assert_eq!(ks.position.sample(0), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn lottie_rejects_malformed_input() {
+        assert!(matches!(
+            scalar::<f32>(r#"{"a": 1, "k": [{"t": 0"#, 1.0),
+            Err(TweenError::ParseError { .. })
+        ));
+        assert_eq!(
+            scalar::<f32>(r#"{"a": 1, "k": [{"s": [1]}]}"#, 1.0).unwrap_err(),
+            TweenError::InvalidImportData
+        );
+        assert_eq!(
+            transform::<f32>(r#"{"p": {"s": true, "x": {"k": 0}, "y": {"k": 0}}}"#, 1.0)
+                .unwrap_err(),
+            TweenError::InvalidImportData
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Converters from external animation formats into keyframe tracks.
+//!
+//! Each importer is behind a cargo feature of the same name and works on plain data, so no
+//! parser dependency is pulled in.
+
+#[cfg(feature = "css")]
+pub mod css;
+#[cfg(feature = "gltf")]
+pub mod gltf;
+#[cfg(feature = "lottie")]
+mod json;
+#[cfg(feature = "lottie")]
+pub mod lottie;
@@ -0,0 +1,138 @@
+use glam::{DMat4, DQuat, DVec2, DVec3, DVec4, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+use crate::lerp::Lerp;
+use crate::vector::VectorSpace;
+
+macro_rules! impl_glam_vector {
+    ($float:ty; $($ty:ty),+) => {
+        $(
+            impl Lerp<$float> for $ty {
+                fn lerp(&self, other: &Self, t: $float) -> Self {
+                    <$ty>::lerp(*self, *other, t)
+                }
+            }
+
+            impl VectorSpace<$float> for $ty {
+                fn zero() -> Self {
+                    <$ty>::ZERO
+                }
+
+                fn plus(&self, other: &Self) -> Self {
+                    *self + *other
+                }
+
+                fn minus(&self, other: &Self) -> Self {
+                    *self - *other
+                }
+
+                fn scaled(&self, factor: $float) -> Self {
+                    *self * factor
+                }
+
+                fn dot(&self, other: &Self) -> $float {
+                    <$ty>::dot(*self, *other)
+                }
+
+                fn length(&self) -> $float {
+                    <$ty>::length(*self)
+                }
+            }
+        )+
+    };
+}
+
+impl_glam_vector!(f32; Vec2, Vec3, Vec3A, Vec4);
+impl_glam_vector!(f64; DVec2, DVec3, DVec4);
+
+/// Shortest-arc spherical interpolation.
+impl Lerp<f32> for Quat {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+/// Shortest-arc spherical interpolation.
+impl Lerp<f64> for DQuat {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+/// Decomposes into scale, rotation and translation, interpolates each (slerping the rotation),
+/// and recomposes. Intended for affine transforms; projection matrices are not preserved.
+impl Lerp<f32> for Mat4 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let (s0, r0, t0) = self.to_scale_rotation_translation();
+        let (s1, r1, t1) = other.to_scale_rotation_translation();
+        Mat4::from_scale_rotation_translation(s0.lerp(s1, t), r0.slerp(r1, t), t0.lerp(t1, t))
+    }
+}
+
+/// Decomposes into scale, rotation and translation, interpolates each (slerping the rotation),
+/// and recomposes. Intended for affine transforms; projection matrices are not preserved.
+impl Lerp<f64> for DMat4 {
+    fn lerp(&self, other: &Self, t: f64) -> Self {
+        let (s0, r0, t0) = self.to_scale_rotation_translation();
+        let (s1, r1, t1) = other.to_scale_rotation_translation();
+        DMat4::from_scale_rotation_translation(s0.lerp(s1, t), r0.slerp(r1, t), t0.lerp(t1, t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{DVec3, Mat4, Quat, Vec2, Vec3};
+
+    use crate::lerp::Lerp;
+    use crate::spring::{SpringConfig, SpringTween};
+    use crate::tween::Tween;
+
+    const EPS: f32 = 1e-4;
+
+    #[test]
+    fn glam_vec_tween() {
+        let mut tween = Tween::new(Vec2::ZERO, Vec2::new(10.0, 20.0), 4);
+        tween.tick();
+        tween.tick();
+        assert!(tween.value().abs_diff_eq(Vec2::new(5.0, 10.0), EPS));
+    }
+
+    #[test]
+    fn glam_dvec_lerp() {
+        let mid = Lerp::lerp(&DVec3::ZERO, &DVec3::splat(2.0), 0.5);
+        assert_eq!(mid, DVec3::ONE);
+    }
+
+    #[test]
+    fn glam_quat_slerp() {
+        let a = Quat::IDENTITY;
+        let b = Quat::from_rotation_z(core::f32::consts::FRAC_PI_2);
+        let mid = Lerp::lerp(&a, &b, 0.5f32);
+        let expected = Quat::from_rotation_z(core::f32::consts::FRAC_PI_4);
+        assert!(mid.abs_diff_eq(expected, EPS));
+    }
+
+    #[test]
+    fn glam_mat4_decomposed() {
+        let a = Mat4::from_translation(Vec3::ZERO);
+        let b = Mat4::from_scale_rotation_translation(
+            Vec3::splat(3.0),
+            Quat::IDENTITY,
+            Vec3::new(10.0, 0.0, 0.0),
+        );
+        let mid = Lerp::lerp(&a, &b, 0.5f32);
+        let (scale, _, translation) = mid.to_scale_rotation_translation();
+        assert!(scale.abs_diff_eq(Vec3::splat(2.0), EPS));
+        assert!(translation.abs_diff_eq(Vec3::new(5.0, 0.0, 0.0), EPS));
+    }
+
+    #[test]
+    fn glam_vector_spring() {
+        let mut spring =
+            SpringTween::new(Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0), SpringConfig::stiff());
+        for _ in 0..600 {
+            spring.tick();
+        }
+        assert!(spring.is_at_rest());
+        assert_eq!(spring.value(), Vec3::new(1.0, 2.0, 3.0));
+    }
+}
@@ -0,0 +1,106 @@
+use mint::{Point2, Point3, Quaternion, Vector2, Vector3, Vector4};
+
+use crate::float::Float;
+use crate::lerp::{slerp_xyzw, Lerp};
+use crate::vector::VectorSpace;
+
+macro_rules! impl_mint_vector {
+    ($($ty:ident { $($field:ident),+ }),+) => {
+        $(
+            impl<F: Float> Lerp<F> for $ty<F> {
+                fn lerp(&self, other: &Self, t: F) -> Self {
+                    $ty { $($field: Float::lerp(self.$field, other.$field, t)),+ }
+                }
+            }
+
+            impl<F: Float> VectorSpace<F> for $ty<F> {
+                fn zero() -> Self {
+                    $ty { $($field: F::zero()),+ }
+                }
+
+                fn plus(&self, other: &Self) -> Self {
+                    $ty { $($field: self.$field + other.$field),+ }
+                }
+
+                fn minus(&self, other: &Self) -> Self {
+                    $ty { $($field: self.$field - other.$field),+ }
+                }
+
+                fn scaled(&self, factor: F) -> Self {
+                    $ty { $($field: self.$field * factor),+ }
+                }
+
+                fn dot(&self, other: &Self) -> F {
+                    F::zero() $(+ self.$field * other.$field)+
+                }
+            }
+        )+
+    };
+}
+
+impl_mint_vector!(
+    Vector2 { x, y },
+    Vector3 { x, y, z },
+    Vector4 { x, y, z, w },
+    Point2 { x, y },
+    Point3 { x, y, z }
+);
+
+/// Shortest-arc spherical interpolation of unit quaternions.
+impl<F: Float> Lerp<F> for Quaternion<F> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        let [x, y, z, w] = slerp_xyzw(
+            [self.v.x, self.v.y, self.v.z, self.s],
+            [other.v.x, other.v.y, other.v.z, other.s],
+            t,
+        );
+        Quaternion {
+            v: Vector3 { x, y, z },
+            s: w,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mint::{Quaternion, Vector2, Vector3};
+
+    use crate::lerp::Lerp;
+    use crate::vector::VectorSpace;
+
+    const EPS: f32 = 1e-4;
+
+    #[test]
+    fn mint_vector_lerp() {
+        let a = Vector2 { x: 0.0f32, y: 0.0 };
+        let b = Vector2 { x: 4.0f32, y: 8.0 };
+        assert_eq!(a.lerp(&b, 0.25), Vector2 { x: 1.0, y: 2.0 });
+        assert!((b.length() - 80.0f32.sqrt()).abs() < EPS);
+    }
+
+    #[test]
+    fn mint_quaternion_slerp() {
+        let half = core::f32::consts::FRAC_PI_4;
+        let a = Quaternion {
+            v: Vector3 {
+                x: 0.0f32,
+                y: 0.0,
+                z: 0.0,
+            },
+            s: 1.0,
+        };
+        // 90 degrees about z.
+        let b = Quaternion {
+            v: Vector3 {
+                x: 0.0,
+                y: 0.0,
+                z: half.sin(),
+            },
+            s: half.cos(),
+        };
+        let mid = a.lerp(&b, 0.5);
+        let quarter = core::f32::consts::FRAC_PI_8;
+        assert!((mid.v.z - quarter.sin()).abs() < EPS);
+        assert!((mid.s - quarter.cos()).abs() < EPS);
+    }
+}
@@ -0,0 +1,10 @@
+//! `Lerp` and `VectorSpace` implementations for third-party math crates.
+//!
+//! Each integration is behind a cargo feature of the same name and stays `no_std`.
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,107 @@
+use nalgebra::{Point, RealField, SMatrix, UnitQuaternion};
+
+use crate::float::Float;
+use crate::lerp::Lerp;
+use crate::vector::VectorSpace;
+
+/// Component-wise interpolation; covers `Vector2`..`Vector6` and fixed-size matrices.
+impl<F: Float + RealField, const R: usize, const C: usize> Lerp<F> for SMatrix<F, R, C> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        self.zip_map(other, |a, b| Float::lerp(a, b, t))
+    }
+}
+
+impl<F: Float + RealField, const R: usize, const C: usize> VectorSpace<F> for SMatrix<F, R, C> {
+    fn zero() -> Self {
+        Self::zeros()
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn scaled(&self, factor: F) -> Self {
+        self * factor
+    }
+
+    fn dot(&self, other: &Self) -> F {
+        SMatrix::dot(self, other)
+    }
+}
+
+impl<F: Float + RealField, const D: usize> Lerp<F> for Point<F, D> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        Point::from(Lerp::lerp(&self.coords, &other.coords, t))
+    }
+}
+
+impl<F: Float + RealField, const D: usize> VectorSpace<F> for Point<F, D> {
+    fn zero() -> Self {
+        Point::origin()
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        Point::from(self.coords + other.coords)
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        Point::from(self.coords - other.coords)
+    }
+
+    fn scaled(&self, factor: F) -> Self {
+        Point::from(self.coords * factor)
+    }
+
+    fn dot(&self, other: &Self) -> F {
+        self.coords.dot(&other.coords)
+    }
+}
+
+/// Shortest-arc spherical interpolation.
+impl<F: Float + RealField> Lerp<F> for UnitQuaternion<F> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        self.slerp(other, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{Point2, UnitQuaternion, Vector3};
+
+    use crate::lerp::Lerp;
+    use crate::spring::{SpringConfig, SpringTween};
+
+    const EPS: f64 = 1e-6;
+
+    #[test]
+    fn nalgebra_vector_lerp() {
+        let a = Vector3::new(0.0f64, 0.0, 0.0);
+        let b = Vector3::new(2.0f64, 4.0, 6.0);
+        assert_eq!(Lerp::lerp(&a, &b, 0.5), Vector3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn nalgebra_point_spring() {
+        let mut spring = SpringTween::new(
+            Point2::new(0.0f64, 0.0),
+            Point2::new(5.0, 5.0),
+            SpringConfig::stiff(),
+        );
+        for _ in 0..600 {
+            spring.tick();
+        }
+        assert!(spring.is_at_rest());
+    }
+
+    #[test]
+    fn nalgebra_quaternion_slerp() {
+        let a = UnitQuaternion::identity();
+        let b = UnitQuaternion::from_euler_angles(0.0f64, 0.0, core::f64::consts::FRAC_PI_2);
+        let mid = Lerp::lerp(&a, &b, 0.5);
+        assert!((mid.angle() - core::f64::consts::FRAC_PI_4).abs() < EPS);
+    }
+}
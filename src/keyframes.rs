@@ -1,11 +1,44 @@
 use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
 
-use crate::easing::Easing;
+use crate::bake::bake;
+use crate::easing::{solve_bezier_x, Easing};
 use crate::error::TweenError;
 use crate::float::Float;
 use crate::lerp::Lerp;
-use crate::loop_mode::LoopMode;
+use crate::loop_mode::{LoopMode, PlayDirection};
+use crate::marker::{Marker, MarkerTrack, SeekEvents};
+use crate::spline::{KeySpline, Spline};
 use crate::state::TweenState;
+use crate::vector::VectorSpace;
+
+/// How the segment leaving a keyframe is interpolated.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Hold this keyframe's value until the next keyframe.
+    Constant,
+    /// Eased lerp, or the keyframe's cubic [`Spline`] when one is set.
+    #[default]
+    Linear,
+    /// Cubic bezier through this keyframe's out-handle and the next keyframe's in-handle,
+    /// evaluated like a Blender F-curve.
+    Bezier,
+}
+
+/// A graph-editor handle: a tick offset from its keyframe and an absolute value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BezierHandle<T, F> {
+    /// Ticks relative to the keyframe; negative for in-handles, positive for out-handles.
+    pub ticks: F,
+    /// Value at the handle.
+    pub value: T,
+}
+
+impl<T, F> BezierHandle<T, F> {
+    pub fn new(ticks: F, value: T) -> Self {
+        Self { ticks, value }
+    }
+}
 
 /// A single point in a keyframed animation.
 #[derive(Clone, Debug)]
@@ -16,6 +49,94 @@ pub struct Keyframe<T: Lerp<F>, F: Float> {
     pub tick: u32,
     /// Easing from this keyframe to the next.
     pub easing: Easing<F>,
+    /// Interpolation from this keyframe to the next.
+    pub interpolation: Interpolation,
+    /// Handle controlling the curve arriving at this keyframe.
+    pub in_handle: Option<BezierHandle<T, F>>,
+    /// Handle controlling the curve leaving this keyframe.
+    pub out_handle: Option<BezierHandle<T, F>>,
+    /// Cubic spline for the segment leaving this keyframe, used with [`Interpolation::Linear`].
+    pub spline: Option<KeySpline<T, F>>,
+}
+
+impl<T: Lerp<F>, F: Float> Keyframe<T, F> {
+    /// Keyframe with linear easing.
+    pub fn new(value: T, tick: u32) -> Self {
+        Self {
+            value,
+            tick,
+            easing: Easing::Linear,
+            interpolation: Interpolation::Linear,
+            in_handle: None,
+            out_handle: None,
+            spline: None,
+        }
+    }
+
+    /// Set the easing from this keyframe to the next.
+    pub fn with_easing(mut self, easing: Easing<F>) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the interpolation from this keyframe to the next.
+    pub fn with_interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Set both bezier handles and switch the outgoing segment to [`Interpolation::Bezier`].
+    ///
+    /// A missing handle on either end of a bezier segment sits on its keyframe.
+    pub fn with_handles(
+        mut self,
+        in_handle: Option<BezierHandle<T, F>>,
+        out_handle: Option<BezierHandle<T, F>>,
+    ) -> Self {
+        self.in_handle = in_handle;
+        self.out_handle = out_handle;
+        self.interpolation = Interpolation::Bezier;
+        self
+    }
+
+    /// Interpolate the segment leaving this keyframe with a cubic spline.
+    ///
+    /// Easing still reshapes time along the segment before the curve is evaluated.
+    pub fn with_spline(mut self, spline: Spline<T, F>) -> Self
+    where
+        T: VectorSpace<F>,
+    {
+        self.spline = Some(KeySpline::new(spline));
+        self
+    }
+
+    /// Spline mode of the segment leaving this keyframe, if any.
+    pub fn spline(&self) -> Option<&Spline<T, F>> {
+        self.spline.as_ref().map(|spline| &spline.mode)
+    }
+}
+
+impl<T: Lerp<F>, F: Float> Keyframe<T, F> {
+    /// Stretch the handle offsets and tangents of this keyframe by `factor`.
+    fn retime(&mut self, factor: F) {
+        for handle in [&mut self.in_handle, &mut self.out_handle]
+            .into_iter()
+            .flatten()
+        {
+            handle.ticks = handle.ticks * factor;
+        }
+        if let Some(spline) = &mut self.spline {
+            if factor > F::zero() {
+                spline.retime(factor);
+            }
+        }
+    }
+}
+
+impl<T: Lerp<F> + Default, F: Float> Default for Keyframe<T, F> {
+    fn default() -> Self {
+        Self::new(T::default(), 0)
+    }
 }
 
 /// Multi-point animation with per-segment easing.
@@ -26,6 +147,9 @@ pub struct Keyframes<T: Lerp<F>, F: Float> {
     state: TweenState,
     loop_mode: LoopMode,
     loops_completed: u32,
+    direction: PlayDirection,
+    base: Option<T>,
+    markers: MarkerTrack,
 }
 
 impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
@@ -41,25 +165,166 @@ impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
             state: TweenState::Playing,
             loop_mode: LoopMode::Once,
             loops_completed: 0,
+            direction: PlayDirection::Forward,
+            base: None,
+            markers: MarkerTrack::new(),
         })
     }
 
+    /// Keyframes placed at normalized positions (0.0 = start, 1.0 = `duration`).
+    ///
+    /// Each keyframe's own `tick` is replaced by its position scaled to `duration`. Bezier
+    /// handle offsets and Hermite tangents are read in the same normalized units.
+    pub fn from_normalized(duration: u32, frames: Vec<(F, Keyframe<T, F>)>) -> Self {
+        Self::try_from_normalized(duration, frames).expect("invalid keyframes")
+    }
+
+    pub fn try_from_normalized(
+        duration: u32,
+        frames: Vec<(F, Keyframe<T, F>)>,
+    ) -> Result<Self, TweenError> {
+        let scale = F::from_f32(duration as f32);
+        let mut placed: Vec<Keyframe<T, F>> = Vec::with_capacity(frames.len());
+        let mut previous = None;
+        for (position, mut frame) in frames {
+            let tick = position.max(F::zero()) * scale + F::half();
+            frame.tick = tick.floor().to_f64() as u32;
+            if let (Some(prev), Some(last)) = (previous, placed.last()) {
+                if prev != position && last.tick == frame.tick {
+                    return Err(TweenError::KeyframeCollision {
+                        index: placed.len(),
+                        tick: frame.tick,
+                    });
+                }
+            }
+            frame.retime(scale);
+            previous = Some(position);
+            placed.push(frame);
+        }
+        Self::try_new(placed)
+    }
+
     pub fn with_loop(mut self, mode: LoopMode) -> Self {
         self.loop_mode = mode;
         self
     }
 
+    /// Builder form of [`set_duration`](Self::set_duration). Panics if keys would merge.
+    pub fn with_duration(mut self, duration: u32) -> Self {
+        self.set_duration(duration)
+            .expect("invalid keyframe duration");
+        self
+    }
+
+    /// Stretch or squash every key proportionally so the track lasts `duration` ticks. Bezier
+    /// handles, Hermite tangents and markers are retimed too, so the curve keeps its shape and
+    /// events stay on it.
+    ///
+    /// Fails, leaving the track unchanged, if rounding would put two keys on one tick.
+    pub fn set_duration(&mut self, duration: u32) -> Result<(), TweenError> {
+        let total = self.total_duration();
+        if total == 0 {
+            return Ok(());
+        }
+        let scale =
+            |tick: u32| ((tick as u64 * duration as u64 + total as u64 / 2) / total as u64) as u32;
+        for (i, window) in self.frames.windows(2).enumerate() {
+            let tick = scale(window[1].tick);
+            if window[0].tick != window[1].tick && scale(window[0].tick) == tick {
+                return Err(TweenError::KeyframeCollision { index: i + 1, tick });
+            }
+        }
+        let factor = F::from_f32(duration as f32 / total as f32);
+        for frame in &mut self.frames {
+            frame.tick = scale(frame.tick);
+            frame.retime(factor);
+        }
+        self.markers.retime(scale);
+        Ok(())
+    }
+
+    /// Treat key values as offsets from `base`, typically the value when playback starts.
+    ///
+    /// The base is added to every key and handle value, so [`keys`](Self::keys) and key edits
+    /// use absolute values from then on.
+    pub fn relative_to(mut self, base: T) -> Self
+    where
+        T: VectorSpace<F>,
+    {
+        self.set_base(base);
+        self
+    }
+
+    /// Re-anchor a relative track, moving every key by the change of base, or make this track
+    /// relative.
+    pub fn set_base(&mut self, base: T)
+    where
+        T: VectorSpace<F>,
+    {
+        let shift = match &self.base {
+            Some(old) => base.minus(old),
+            None => base.clone(),
+        };
+        for frame in &mut self.frames {
+            frame.value = frame.value.plus(&shift);
+            for handle in [&mut frame.in_handle, &mut frame.out_handle]
+                .into_iter()
+                .flatten()
+            {
+                handle.value = handle.value.plus(&shift);
+            }
+        }
+        self.base = Some(base);
+    }
+
+    /// Base value of a relative track.
+    pub fn base(&self) -> Option<&T> {
+        self.base.as_ref()
+    }
+
+    /// Builder form of [`add_marker`](Self::add_marker).
+    pub fn with_marker(mut self, tick: u32, event: u32) -> Self {
+        self.add_marker(tick, event);
+        self
+    }
+
+    /// Add an event marker, reported by [`fired_events`](Self::fired_events) whenever playback
+    /// crosses `tick`, on every loop iteration and in either direction.
+    pub fn add_marker(&mut self, tick: u32, event: u32) {
+        self.markers.add(Marker::new(tick, event));
+    }
+
+    /// Markers, ordered by tick.
+    pub fn markers(&self) -> &[Marker] {
+        self.markers.markers()
+    }
+
+    /// Markers crossed by the latest `tick`, `tick_n` or `seek`, in the order playback passed
+    /// them.
+    pub fn fired_events(&self) -> &[Marker] {
+        self.markers.fired()
+    }
+
+    /// Whether seeking fires the markers it jumps over. Seeks are silent by default.
+    pub fn with_seek_events(mut self, seek_events: SeekEvents) -> Self {
+        self.markers.set_seek_events(seek_events);
+        self
+    }
+
     /// Advance by one tick and return interpolated value.
     pub fn tick(&mut self) -> T {
         assert!(!self.frames.is_empty(), "Keyframes cannot be empty");
         if self.state != TweenState::Playing {
+            self.markers.clear();
             return self.value();
         }
 
         let total = self.total_duration();
+        let from = self.position();
         if total > 0 && self.elapsed < total {
             self.elapsed += 1;
         }
+        self.markers.step(from, self.position());
 
         let value = self.value();
         if self.elapsed >= total {
@@ -68,24 +333,91 @@ impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
         value
     }
 
+    /// Advance by `n` ticks as one step, so [`fired_events`](Self::fired_events) reports every
+    /// marker crossed on the way. Returns the value reached.
+    pub fn tick_n(&mut self, n: u32) -> T {
+        self.markers.hold();
+        let mut value = None;
+        for _ in 0..n {
+            value = Some(self.tick());
+        }
+        self.markers.release();
+        value.unwrap_or_else(|| self.value())
+    }
+
     pub fn value(&self) -> T {
+        self.sample(self.position())
+    }
+
+    /// Current playback direction. Backward legs walk the keys from last to first.
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current value.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight, continuing from the current value. A finished track plays
+    /// back the way it came.
+    pub fn reverse(&mut self) {
+        self.direction = self.direction.reversed();
+        let total = self.total_duration();
+        self.elapsed = total - self.elapsed.min(total);
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a leg toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+    }
+
+    /// Jump to a tick on the track, keeping the current direction.
+    pub fn seek(&mut self, tick: u32) {
+        let total = self.total_duration();
+        let from = self.position();
+        let tick = tick.min(total);
+        self.elapsed = match self.direction {
+            PlayDirection::Forward => tick,
+            PlayDirection::Backward => total - tick,
+        };
+        self.markers.seek(from, tick);
+        if self.elapsed < total {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Tick on the track the playhead is at.
+    fn position(&self) -> u32 {
+        match self.direction {
+            PlayDirection::Forward => self.elapsed,
+            PlayDirection::Backward => self.total_duration().saturating_sub(self.elapsed),
+        }
+    }
+
+    /// Value at an arbitrary tick, independent of the playback position.
+    pub fn sample(&self, elapsed: u32) -> T {
         assert!(!self.frames.is_empty(), "Keyframes cannot be empty");
         if self.frames.len() == 1 {
             return self.frames[0].value.clone();
         }
 
-        if self.elapsed <= self.frames[0].tick {
+        if elapsed <= self.frames[0].tick {
             return self.frames[0].value.clone();
         }
 
         let last = self.frames.len() - 1;
-        if self.elapsed >= self.frames[last].tick {
+        if elapsed >= self.frames[last].tick {
             return self.frames[last].value.clone();
         }
 
-        let idx = self
-            .frames
-            .partition_point(|frame| frame.tick <= self.elapsed);
+        let idx = self.frames.partition_point(|frame| frame.tick <= elapsed);
         let i = idx.saturating_sub(1);
         let a = &self.frames[i];
         let b = &self.frames[i + 1];
@@ -94,10 +426,131 @@ impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
             return b.value.clone();
         }
 
-        let local_elapsed = self.elapsed.saturating_sub(a.tick);
+        let local_elapsed = elapsed.saturating_sub(a.tick);
         let raw_t = F::from_f32(local_elapsed as f32 / segment_duration as f32);
         let eased_t = a.easing.evaluate(raw_t);
-        a.value.lerp(&b.value, eased_t)
+        match (a.interpolation, &a.spline) {
+            (Interpolation::Constant, _) => a.value.clone(),
+            (Interpolation::Bezier, _) => bezier_segment(a, b, eased_t),
+            (Interpolation::Linear, Some(spline)) => spline.segment(&self.frames, i, eased_t),
+            (Interpolation::Linear, None) => a.value.lerp(&b.value, eased_t),
+        }
+    }
+
+    /// Evenly resample into linear keys every `interval` ticks, keeping the markers.
+    pub fn resample(&self, interval: u32) -> Keyframes<T, F> {
+        bake(self.total_duration(), interval, |tick| self.sample(tick)).with_markers_of(self)
+    }
+
+    /// Copy `source`'s markers onto this track, for tracks derived from it.
+    pub(crate) fn with_markers_of(mut self, source: &Self) -> Self {
+        for marker in source.markers() {
+            self.add_marker(marker.tick, marker.event);
+        }
+        self
+    }
+
+    /// All keyframes, in tick order.
+    pub fn keys(&self) -> &[Keyframe<T, F>] {
+        &self.frames
+    }
+
+    pub fn key(&self, index: usize) -> Option<&Keyframe<T, F>> {
+        self.frames.get(index)
+    }
+
+    /// Insert a keyframe after any existing keys on the same tick and return its index.
+    ///
+    /// Edits never touch the playback position; a running instance continues from the same
+    /// elapsed tick on the edited curve.
+    pub fn insert_key(&mut self, key: Keyframe<T, F>) -> usize {
+        let index = self.frames.partition_point(|frame| frame.tick <= key.tick);
+        self.frames.insert(index, key);
+        index
+    }
+
+    /// Remove and return the keyframe at `index`. The last remaining key cannot be removed.
+    pub fn remove_key(&mut self, index: usize) -> Result<Keyframe<T, F>, TweenError> {
+        self.check_index(index)?;
+        if self.frames.len() == 1 {
+            return Err(TweenError::EmptyKeyframes);
+        }
+        Ok(self.frames.remove(index))
+    }
+
+    /// Move the keyframe at `index` to `tick`. Keys keep their order, so the new tick must lie
+    /// between the neighbouring keys' ticks.
+    pub fn move_key(&mut self, index: usize, tick: u32) -> Result<(), TweenError> {
+        self.check_index(index)?;
+        if let Some(prev) = index.checked_sub(1).map(|i| &self.frames[i]) {
+            if tick < prev.tick {
+                return Err(TweenError::KeyframeOutOfOrder {
+                    index,
+                    tick,
+                    prev_tick: prev.tick,
+                });
+            }
+        }
+        if let Some(next) = self.frames.get(index + 1) {
+            if next.tick < tick {
+                return Err(TweenError::KeyframeOutOfOrder {
+                    index: index + 1,
+                    tick: next.tick,
+                    prev_tick: tick,
+                });
+            }
+        }
+        self.frames[index].tick = tick;
+        Ok(())
+    }
+
+    pub fn set_key_value(&mut self, index: usize, value: T) -> Result<(), TweenError> {
+        self.check_index(index)?;
+        self.frames[index].value = value;
+        Ok(())
+    }
+
+    /// Index of the keyframe closest to `tick`, preferring the earlier key on a tie.
+    pub fn nearest_key(&self, tick: u32) -> usize {
+        let after = self.frames.partition_point(|frame| frame.tick < tick);
+        if after == 0 {
+            return 0;
+        }
+        if after == self.frames.len() {
+            return after - 1;
+        }
+        let before = after - 1;
+        if tick - self.frames[before].tick <= self.frames[after].tick - tick {
+            before
+        } else {
+            after
+        }
+    }
+
+    /// Keyframes whose ticks fall within `range`.
+    pub fn keys_in_range(&self, range: impl RangeBounds<u32>) -> &[Keyframe<T, F>] {
+        let start = match range.start_bound() {
+            Bound::Included(&t) => self.frames.partition_point(|frame| frame.tick < t),
+            Bound::Excluded(&t) => self.frames.partition_point(|frame| frame.tick <= t),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&t) => self.frames.partition_point(|frame| frame.tick <= t),
+            Bound::Excluded(&t) => self.frames.partition_point(|frame| frame.tick < t),
+            Bound::Unbounded => self.frames.len(),
+        };
+        &self.frames[start..end.max(start)]
+    }
+
+    fn check_index(&self, index: usize) -> Result<(), TweenError> {
+        if index < self.frames.len() {
+            Ok(())
+        } else {
+            Err(TweenError::KeyframeIndexOutOfRange {
+                index,
+                len: self.frames.len(),
+            })
+        }
     }
 
     pub fn total_duration(&self) -> u32 {
@@ -109,17 +562,24 @@ impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
         if total == 0 {
             return F::one();
         }
-        F::from_f32(self.elapsed.min(total) as f32 / total as f32)
+        F::from_f32(self.position().min(total) as f32 / total as f32)
     }
 
     pub fn is_finished(&self) -> bool {
         self.state == TweenState::Finished
     }
 
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
     pub fn reset(&mut self) {
         self.elapsed = 0;
         self.state = TweenState::Playing;
         self.loops_completed = 0;
+        self.direction = PlayDirection::Forward;
+        self.markers.clear();
+        self.markers.restart();
     }
 
     fn on_iteration_complete(&mut self) {
@@ -133,11 +593,18 @@ impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
                     self.state = TweenState::Finished;
                 } else {
                     self.elapsed = 0;
+                    self.markers.restart();
                 }
             }
-            LoopMode::Infinite | LoopMode::PingPong => {
+            LoopMode::Infinite => {
                 self.loops_completed += 1;
                 self.elapsed = 0;
+                self.markers.restart();
+            }
+            LoopMode::PingPong => {
+                self.loops_completed += 1;
+                self.elapsed = 0;
+                self.direction = self.direction.reversed();
             }
             LoopMode::PingPongCount(count) => {
                 self.loops_completed += 1;
@@ -146,12 +613,45 @@ impl<T: Lerp<F> + Clone, F: Float> Keyframes<T, F> {
                     self.state = TweenState::Finished;
                 } else {
                     self.elapsed = 0;
+                    self.direction = self.direction.reversed();
                 }
             }
         }
     }
 }
 
+/// Evaluate the bezier segment from `a` to `b` at normalized time `t`, Blender style:
+/// handles reaching past each other are shortened proportionally, the time curve is solved
+/// for its parameter, and the value curve is evaluated at that parameter.
+fn bezier_segment<T: Lerp<F> + Clone, F: Float>(a: &Keyframe<T, F>, b: &Keyframe<T, F>, t: F) -> T {
+    let duration = F::from_f32(b.tick.saturating_sub(a.tick) as f32);
+    let (mut out_ticks, mut out_value) = match &a.out_handle {
+        Some(handle) => (handle.ticks.max(F::zero()), handle.value.clone()),
+        None => (F::zero(), a.value.clone()),
+    };
+    let (mut in_ticks, mut in_value) = match &b.in_handle {
+        Some(handle) => ((-handle.ticks).max(F::zero()), handle.value.clone()),
+        None => (F::zero(), b.value.clone()),
+    };
+
+    let reach = out_ticks + in_ticks;
+    if reach > duration {
+        let factor = duration / reach;
+        out_ticks = out_ticks * factor;
+        in_ticks = in_ticks * factor;
+        out_value = a.value.lerp(&out_value, factor);
+        in_value = b.value.lerp(&in_value, factor);
+    }
+
+    let s = solve_bezier_x(t, out_ticks / duration, F::one() - in_ticks / duration);
+    let p01 = a.value.lerp(&out_value, s);
+    let p12 = out_value.lerp(&in_value, s);
+    let p23 = in_value.lerp(&b.value, s);
+    let p012 = p01.lerp(&p12, s);
+    let p123 = p12.lerp(&p23, s);
+    p012.lerp(&p123, s)
+}
+
 fn validate_frames<T: Lerp<F>, F: Float>(frames: &[Keyframe<T, F>]) -> Result<(), TweenError> {
     if frames.is_empty() {
         return Err(TweenError::EmptyKeyframes);
@@ -175,8 +675,11 @@ mod tests {
     use alloc::vec;
 
     use crate::easing::Easing;
-    use crate::keyframes::{Keyframe, Keyframes};
-    use crate::loop_mode::LoopMode;
+    use crate::error::TweenError;
+    use crate::keyframes::{BezierHandle, Interpolation, Keyframe, Keyframes};
+    use crate::loop_mode::{LoopMode, PlayDirection};
+    use crate::marker::{Marker, SeekEvents};
+    use crate::spline::Spline;
 
     const EPS: f32 = 1e-4;
 
@@ -191,11 +694,13 @@ mod tests {
                 value: 0.0f32,
                 tick: 0,
                 easing: Easing::Linear,
+                ..Default::default()
             },
             Keyframe {
                 value: 100.0f32,
                 tick: 10,
                 easing: Easing::Linear,
+                ..Default::default()
             },
         ]);
         let mut value = 0.0;
@@ -212,16 +717,19 @@ mod tests {
                 value: 0.0f32,
                 tick: 0,
                 easing: Easing::Linear,
+                ..Default::default()
             },
             Keyframe {
                 value: 100.0f32,
                 tick: 5,
                 easing: Easing::Linear,
+                ..Default::default()
             },
             Keyframe {
                 value: 50.0f32,
                 tick: 10,
                 easing: Easing::Linear,
+                ..Default::default()
             },
         ]);
         let mut value = 0.0;
@@ -238,16 +746,19 @@ mod tests {
                 value: 0.0f32,
                 tick: 0,
                 easing: Easing::EaseInQuad,
+                ..Default::default()
             },
             Keyframe {
                 value: 100.0f32,
                 tick: 10,
                 easing: Easing::EaseOutQuad,
+                ..Default::default()
             },
             Keyframe {
                 value: 0.0f32,
                 tick: 20,
                 easing: Easing::Linear,
+                ..Default::default()
             },
         ]);
 
@@ -270,11 +781,13 @@ mod tests {
                 value: 0.0f32,
                 tick: 0,
                 easing: Easing::Linear,
+                ..Default::default()
             },
             Keyframe {
                 value: 10.0f32,
                 tick: 2,
                 easing: Easing::Linear,
+                ..Default::default()
             },
         ])
         .with_loop(LoopMode::Infinite);
@@ -284,4 +797,324 @@ mod tests {
         }
         assert!(!keyframes.is_finished());
     }
+
+    #[test]
+    fn keyframes_ping_pong_mirrors_forward_leg() {
+        let mut keyframes = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0),
+            Keyframe::new(10.0, 4).with_easing(Easing::EaseInQuad),
+            Keyframe::new(-5.0, 10).with_interpolation(Interpolation::Constant),
+            Keyframe::new(3.0, 12),
+        ])
+        .with_loop(LoopMode::PingPongCount(1));
+        let values = sample_all(&mut keyframes);
+        assert_eq!(values.len(), 25);
+        for offset in 0..=12 {
+            assert!(approx(values[12 + offset], values[12 - offset]));
+        }
+        assert_eq!(keyframes.direction(), PlayDirection::Backward);
+    }
+
+    #[test]
+    fn keyframes_reverse_mid_flight() {
+        let mut keyframes =
+            Keyframes::new(vec![Keyframe::new(0.0f32, 0), Keyframe::new(10.0f32, 10)]);
+        for _ in 0..4 {
+            keyframes.tick();
+        }
+        keyframes.reverse();
+        assert!(approx(keyframes.value(), 4.0));
+        assert!(approx(keyframes.progress(), 0.4));
+        assert!(approx(keyframes.tick(), 3.0));
+
+        for _ in 0..3 {
+            keyframes.tick();
+        }
+        assert!(keyframes.is_finished());
+        keyframes.set_direction(PlayDirection::Forward);
+        assert!(!keyframes.is_finished());
+        assert!(approx(keyframes.tick(), 1.0));
+    }
+
+    fn sample_all(keyframes: &mut Keyframes<f32, f32>) -> alloc::vec::Vec<f32> {
+        let mut out = vec![keyframes.value()];
+        while !keyframes.is_finished() {
+            out.push(keyframes.tick());
+        }
+        out
+    }
+
+    #[test]
+    fn keyframes_constant_holds() {
+        let mut keyframes = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0).with_interpolation(Interpolation::Constant),
+            Keyframe::new(10.0f32, 4),
+            Keyframe::new(20.0f32, 8),
+        ]);
+        let v = sample_all(&mut keyframes);
+        assert!(v[..4].iter().all(|&x| approx(x, 0.0)));
+        assert!(approx(v[4], 10.0));
+        assert!(approx(v[6], 15.0));
+    }
+
+    #[test]
+    fn keyframes_bezier_handles() {
+        // Flat handles a third of the way in: a symmetric ease-in-out.
+        let mut keyframes = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0).with_handles(None, Some(BezierHandle::new(10.0 / 3.0, 0.0))),
+            Keyframe::new(10.0f32, 10)
+                .with_handles(Some(BezierHandle::new(-10.0 / 3.0, 10.0)), None),
+        ]);
+        let v = sample_all(&mut keyframes);
+        assert!(approx(v[5], 5.0));
+        assert!(v[2] < 2.0);
+        assert!(v[8] > 8.0);
+        for i in 0..=10 {
+            assert!(approx(v[i] + v[10 - i], 10.0));
+        }
+    }
+
+    #[test]
+    fn keyframes_bezier_matches_blender_solution() {
+        // Handles at (2, 8) and (8, 10): the time curve is symmetric, so tick 5 is s = 0.5,
+        // where the value curve gives 3/8 * 8 + 3/8 * 10 + 1/8 * 10 = 8.
+        let mut keyframes = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0).with_handles(None, Some(BezierHandle::new(2.0, 8.0))),
+            Keyframe::new(10.0f32, 10).with_handles(Some(BezierHandle::new(-2.0, 10.0)), None),
+        ]);
+        let v = sample_all(&mut keyframes);
+        assert!(approx(v[5], 8.0));
+    }
+
+    #[test]
+    fn keyframes_bezier_overlong_handles_are_clamped() {
+        let mut keyframes = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0).with_handles(None, Some(BezierHandle::new(30.0, 0.0))),
+            Keyframe::new(10.0f32, 10).with_handles(Some(BezierHandle::new(-30.0, 10.0)), None),
+        ]);
+        let v = sample_all(&mut keyframes);
+        assert!(v.windows(2).all(|w| w[1] >= w[0] - EPS));
+        assert!(approx(v[5], 5.0));
+        assert!(approx(v[10], 10.0));
+    }
+
+    #[test]
+    fn keyframes_edit_keys() {
+        let mut keyframes =
+            Keyframes::new(vec![Keyframe::new(0.0f32, 0), Keyframe::new(10.0f32, 10)]);
+        for _ in 0..5 {
+            keyframes.tick();
+        }
+        assert!(approx(keyframes.value(), 5.0));
+
+        assert_eq!(keyframes.insert_key(Keyframe::new(50.0, 5)), 1);
+        assert!(approx(keyframes.value(), 50.0));
+        keyframes.set_key_value(1, 20.0).unwrap();
+        assert!(approx(keyframes.value(), 20.0));
+
+        keyframes.move_key(1, 8).unwrap();
+        assert!(approx(keyframes.value(), 12.5));
+        assert_eq!(
+            keyframes.move_key(1, 12),
+            Err(TweenError::KeyframeOutOfOrder {
+                index: 2,
+                tick: 10,
+                prev_tick: 12,
+            })
+        );
+
+        let removed = keyframes.remove_key(1).unwrap();
+        assert_eq!(removed.tick, 8);
+        assert!(approx(keyframes.value(), 5.0));
+        keyframes.tick();
+        assert!(approx(keyframes.value(), 6.0));
+    }
+
+    #[test]
+    fn keyframes_edit_errors() {
+        let mut keyframes = Keyframes::new(vec![Keyframe::new(0.0f32, 0)]);
+        assert_eq!(
+            keyframes.remove_key(0).unwrap_err(),
+            TweenError::EmptyKeyframes
+        );
+        assert_eq!(
+            keyframes.set_key_value(2, 1.0),
+            Err(TweenError::KeyframeIndexOutOfRange { index: 2, len: 1 })
+        );
+    }
+
+    #[test]
+    fn keyframes_query_keys() {
+        let keyframes = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0),
+            Keyframe::new(1.0f32, 10),
+            Keyframe::new(2.0f32, 20),
+            Keyframe::new(3.0f32, 30),
+        ]);
+        assert_eq!(keyframes.nearest_key(4), 0);
+        assert_eq!(keyframes.nearest_key(5), 0);
+        assert_eq!(keyframes.nearest_key(6), 1);
+        assert_eq!(keyframes.nearest_key(99), 3);
+        assert_eq!(keyframes.keys_in_range(10..30).len(), 2);
+        assert_eq!(keyframes.keys_in_range(10..=30).len(), 3);
+        assert_eq!(keyframes.keys_in_range(11..19).len(), 0);
+        assert_eq!(keyframes.keys_in_range(..).len(), 4);
+        assert_eq!(keyframes.key(2).map(|k| k.tick), Some(20));
+    }
+
+    #[test]
+    fn keyframes_normalized_positions() {
+        let shake = |duration| {
+            Keyframes::from_normalized(
+                duration,
+                vec![
+                    (0.0, Keyframe::new(0.0f32, 0)),
+                    (0.25, Keyframe::new(1.0, 0)),
+                    (0.75, Keyframe::new(-1.0, 0)),
+                    (1.0, Keyframe::new(0.0, 0)),
+                ],
+            )
+        };
+        let short = shake(8);
+        let ticks: alloc::vec::Vec<u32> = short.keys().iter().map(|k| k.tick).collect();
+        assert_eq!(ticks, [0, 2, 6, 8]);
+        let long = shake(40);
+        assert_eq!(long.total_duration(), 40);
+        assert!(approx(long.sample(10), 1.0));
+        assert!(approx(long.sample(20), 0.0));
+
+        let stretched = short.with_duration(80);
+        let ticks: alloc::vec::Vec<u32> = stretched.keys().iter().map(|k| k.tick).collect();
+        assert_eq!(ticks, [0, 20, 60, 80]);
+
+        let crowded = Keyframes::try_from_normalized(
+            2,
+            vec![
+                (0.0, Keyframe::new(0.0f32, 0)),
+                (0.2, Keyframe::new(1.0, 0)),
+                (1.0, Keyframe::new(0.0, 0)),
+            ],
+        );
+        assert_eq!(
+            crowded.unwrap_err(),
+            TweenError::KeyframeCollision { index: 1, tick: 0 }
+        );
+    }
+
+    #[test]
+    fn keyframes_retime_keeps_curve_shape() {
+        let bezier = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0).with_handles(None, Some(BezierHandle::new(2.0, 8.0))),
+            Keyframe::new(10.0f32, 10).with_handles(Some(BezierHandle::new(-2.0, 10.0)), None),
+        ]);
+        let stretched = bezier.clone().with_duration(30);
+        for tick in 0..=10 {
+            assert!(approx(stretched.sample(tick * 3), bezier.sample(tick)));
+        }
+
+        let hermite = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0).with_spline(Spline::Hermite {
+                in_tangent: 0.0,
+                out_tangent: 3.0,
+            }),
+            Keyframe::new(10.0, 10).with_spline(Spline::Hermite {
+                in_tangent: 0.0,
+                out_tangent: 0.0,
+            }),
+        ]);
+        let squashed = hermite.clone().with_duration(5);
+        for tick in 0..=5 {
+            assert!(approx(squashed.sample(tick), hermite.sample(tick * 2)));
+        }
+
+        let mut keys = Keyframes::new(vec![
+            Keyframe::new(0.0f32, 0),
+            Keyframe::new(5.0, 1),
+            Keyframe::new(1.0, 2),
+            Keyframe::new(2.0, 10),
+        ]);
+        assert_eq!(
+            keys.set_duration(3),
+            Err(TweenError::KeyframeCollision { index: 1, tick: 0 })
+        );
+        assert!(approx(keys.sample(1), 5.0));
+        keys.set_duration(20).unwrap();
+        assert!(approx(keys.sample(2), 5.0));
+
+        let mut marked = Keyframes::new(vec![Keyframe::new(0.0f32, 0), Keyframe::new(1.0, 10)])
+            .with_marker(5, 1)
+            .with_marker(10, 2);
+        marked.set_duration(20).unwrap();
+        assert_eq!(marked.markers(), [Marker::new(10, 1), Marker::new(20, 2)]);
+        marked.seek(19);
+        marked.tick();
+        assert_eq!(marked.fired_events(), [Marker::new(20, 2)]);
+    }
+
+    #[test]
+    fn keyframes_relative_offsets() {
+        let bounce = Keyframes::new(vec![
+            Keyframe::new((0.0f32, 0.0f32), 0),
+            Keyframe::new((0.0, -10.0), 5),
+            Keyframe::new((0.0, 0.0), 10),
+        ]);
+        let mut a = bounce.clone().relative_to((100.0, 50.0));
+        for _ in 0..5 {
+            a.tick();
+        }
+        assert_eq!(a.value(), (100.0, 40.0));
+        assert_eq!(a.base(), Some(&(100.0, 50.0)));
+
+        let mut b = bounce.relative_to((-3.0, 7.0));
+        assert_eq!(b.value(), (-3.0, 7.0));
+        b.set_base((0.0, 0.0));
+        assert_eq!(b.sample(5), (0.0, -10.0));
+    }
+
+    #[test]
+    fn keyframes_markers_fire_each_iteration_and_leg() {
+        let mut track = Keyframes::new(vec![Keyframe::new(0.0f32, 0), Keyframe::new(4.0, 4)])
+            .with_marker(0, 10)
+            .with_marker(2, 20)
+            .with_marker(4, 30)
+            .with_loop(LoopMode::Count(2));
+        let mut fired = vec![];
+        while !track.is_finished() {
+            track.tick();
+            fired.extend(track.fired_events().iter().map(|m| m.event));
+        }
+        assert_eq!(fired, [10, 20, 30, 10, 20, 30]);
+
+        track = track.with_loop(LoopMode::PingPong);
+        track.reset();
+        fired.clear();
+        for _ in 0..8 {
+            track.tick();
+            fired.extend(track.fired_events().iter().map(|m| m.event));
+        }
+        assert_eq!(fired, [10, 20, 30, 20, 10]);
+    }
+
+    #[test]
+    fn keyframes_markers_on_seek_and_reverse() {
+        let mut track = Keyframes::new(vec![Keyframe::new(0.0f32, 0), Keyframe::new(8.0, 8)])
+            .with_marker(3, 1)
+            .with_marker(3, 2)
+            .with_marker(6, 3);
+        track.seek(7);
+        assert!(track.fired_events().is_empty());
+        assert_eq!(track.value(), 7.0);
+        track.reverse();
+        track.tick();
+        assert_eq!(track.value(), 6.0);
+        assert_eq!(track.fired_events(), [track.markers()[2]]);
+
+        let mut track = track.with_seek_events(SeekEvents::Fire);
+        track.seek(0);
+        let events: alloc::vec::Vec<u32> = track.fired_events().iter().map(|m| m.event).collect();
+        assert_eq!(events, [2, 1]);
+        assert_eq!(track.direction(), PlayDirection::Backward);
+        track.tick();
+        assert!(track.fired_events().is_empty());
+    }
 }
@@ -12,43 +12,245 @@ impl<F: Float> Lerp<F> for F {
     }
 }
 
-impl<F: Float> Lerp<F> for (F, F) {
+macro_rules! impl_lerp_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<F: Float, $($name: Lerp<F>),+> Lerp<F> for ($($name,)+) {
+            fn lerp(&self, other: &Self, t: F) -> Self {
+                ($(self.$idx.lerp(&other.$idx, t),)+)
+            }
+        }
+    };
+}
+
+impl_lerp_tuple!(A 0);
+impl_lerp_tuple!(A 0, B 1);
+impl_lerp_tuple!(A 0, B 1, C 2);
+impl_lerp_tuple!(A 0, B 1, C 2, D 3);
+impl_lerp_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_lerp_tuple!(A 0, B 1, C 2, D 3, E 4, G 5);
+
+impl<F: Float, T: Lerp<F>, const N: usize> Lerp<F> for [T; N] {
     fn lerp(&self, other: &Self, t: F) -> Self {
-        (
-            Float::lerp(self.0, other.0, t),
-            Float::lerp(self.1, other.1, t),
-        )
+        core::array::from_fn(|i| self[i].lerp(&other[i], t))
+    }
+}
+
+/// How interpolated integers are rounded back to whole numbers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round to the nearest integer, halfway cases away from zero.
+    #[default]
+    Nearest,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero.
+    Trunc,
+}
+
+impl Rounding {
+    /// Round `v` to a whole number according to this mode.
+    pub fn apply<F: Float>(self, v: F) -> F {
+        match self {
+            Self::Nearest => {
+                if v < F::zero() {
+                    -(-v + F::half()).floor()
+                } else {
+                    (v + F::half()).floor()
+                }
+            }
+            Self::Floor => v.floor(),
+            Self::Ceil => -(-v).floor(),
+            Self::Trunc => {
+                if v < F::zero() {
+                    -(-v).floor()
+                } else {
+                    v.floor()
+                }
+            }
+        }
+    }
+}
+
+/// Integer value interpolated with an explicit rounding mode.
+///
+/// Plain integers round to nearest; wrap them in `Rounded` to floor, ceil or truncate instead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rounded<I> {
+    pub value: I,
+    pub rounding: Rounding,
+}
+
+impl<I> Rounded<I> {
+    pub fn new(value: I, rounding: Rounding) -> Self {
+        Self { value, rounding }
+    }
+}
+
+fn lerp_integer<F: Float>(a: f64, b: f64, t: F, rounding: Rounding) -> f64 {
+    rounding.apply(a + (b - a) * t.to_f64())
+}
+
+/// Integers wider than the 53 bits `f64` holds exactly.
+trait WideInteger: Copy + Ord {
+    fn distance(self, other: Self) -> u128;
+
+    fn is_negative(self) -> bool;
+
+    /// Move `steps` toward `other`; `steps` must not exceed the distance.
+    fn toward(self, other: Self, steps: u128) -> Self;
+}
+
+/// Interpolate as an exact whole-step offset from `a`, so endpoints and large values stay exact.
+/// Overshooting `t` falls back to saturating `f64` interpolation.
+fn lerp_wide<I: WideInteger, F: Float>(a: I, b: I, t: F, rounding: Rounding) -> Option<I> {
+    if t < F::zero() || t > F::one() {
+        return None;
     }
+    let distance = a.distance(b);
+    let offset = distance as f64 * t.to_f64();
+    let whole = offset.floor();
+    let fraction = offset - whole;
+    let steps = (whole as u128).min(distance);
+    // Step counts toward `b` of the integers just below and above the exact value.
+    let (below, above, fraction) = if b >= a {
+        (steps, steps.saturating_add(1), fraction)
+    } else if fraction > 0.0 {
+        (steps.saturating_add(1), steps, 1.0 - fraction)
+    } else {
+        (steps, steps, fraction)
+    };
+    let negative = a.toward(b, below.min(distance)).is_negative();
+    let round_up = match rounding {
+        Rounding::Nearest if negative => fraction > 0.5,
+        Rounding::Nearest => fraction >= 0.5,
+        Rounding::Floor => false,
+        Rounding::Ceil => fraction > 0.0,
+        Rounding::Trunc => negative && fraction > 0.0,
+    };
+    let steps = if round_up { above } else { below };
+    Some(a.toward(b, steps.min(distance)))
+}
+
+macro_rules! impl_lerp_integer {
+    ($($ty:ty),+) => {
+        $(
+            impl<F: Float> Lerp<F> for $ty {
+                fn lerp(&self, other: &Self, t: F) -> Self {
+                    lerp_integer(*self as f64, *other as f64, t, Rounding::Nearest) as $ty
+                }
+            }
+
+            impl<F: Float> Lerp<F> for Rounded<$ty> {
+                fn lerp(&self, other: &Self, t: F) -> Self {
+                    let value = lerp_integer(self.value as f64, other.value as f64, t, self.rounding);
+                    Self::new(value as $ty, self.rounding)
+                }
+            }
+        )+
+    };
 }
 
-impl<F: Float> Lerp<F> for (F, F, F) {
+macro_rules! impl_lerp_wide_integer {
+    (@negative $value:ident, signed) => {
+        $value < 0
+    };
+    (@negative $value:ident, unsigned) => {
+        false
+    };
+    ($($ty:ty => $signed:tt),+) => {
+        $(
+            impl WideInteger for $ty {
+                fn distance(self, other: Self) -> u128 {
+                    self.abs_diff(other) as u128
+                }
+
+                fn is_negative(self) -> bool {
+                    impl_lerp_wide_integer!(@negative self, $signed)
+                }
+
+                fn toward(self, other: Self, steps: u128) -> Self {
+                    // The result lies between `self` and `other`, so wrapping arithmetic is exact.
+                    if other >= self {
+                        self.wrapping_add(steps as $ty)
+                    } else {
+                        self.wrapping_sub(steps as $ty)
+                    }
+                }
+            }
+
+            impl<F: Float> Lerp<F> for $ty {
+                fn lerp(&self, other: &Self, t: F) -> Self {
+                    lerp_wide(*self, *other, t, Rounding::Nearest).unwrap_or_else(|| {
+                        lerp_integer(*self as f64, *other as f64, t, Rounding::Nearest) as $ty
+                    })
+                }
+            }
+
+            impl<F: Float> Lerp<F> for Rounded<$ty> {
+                fn lerp(&self, other: &Self, t: F) -> Self {
+                    let value = lerp_wide(self.value, other.value, t, self.rounding)
+                        .unwrap_or_else(|| {
+                            let (a, b) = (self.value as f64, other.value as f64);
+                            lerp_integer(a, b, t, self.rounding) as $ty
+                        });
+                    Self::new(value, self.rounding)
+                }
+            }
+        )+
+    };
+}
+
+impl_lerp_integer!(i8, i16, i32, u8, u16, u32);
+impl_lerp_wide_integer!(
+    i64 => signed,
+    i128 => signed,
+    isize => signed,
+    u64 => unsigned,
+    u128 => unsigned,
+    usize => unsigned
+);
+
+fn step<T: Clone, F: Float>(a: &T, b: &T, t: F) -> T {
+    if t < F::half() {
+        a.clone()
+    } else {
+        b.clone()
+    }
+}
+
+impl<F: Float> Lerp<F> for bool {
     fn lerp(&self, other: &Self, t: F) -> Self {
-        (
-            Float::lerp(self.0, other.0, t),
-            Float::lerp(self.1, other.1, t),
-            Float::lerp(self.2, other.2, t),
-        )
+        step(self, other, t)
     }
 }
 
-impl<F: Float> Lerp<F> for (F, F, F, F) {
+impl<F: Float> Lerp<F> for char {
     fn lerp(&self, other: &Self, t: F) -> Self {
-        (
-            Float::lerp(self.0, other.0, t),
-            Float::lerp(self.1, other.1, t),
-            Float::lerp(self.2, other.2, t),
-            Float::lerp(self.3, other.3, t),
-        )
+        step(self, other, t)
     }
 }
 
-impl<F: Float, const N: usize> Lerp<F> for [F; N] {
+/// Interpolates inner values when both sides are `Some`, otherwise steps at `t = 0.5`.
+impl<F: Float, T: Lerp<F> + Clone> Lerp<F> for Option<T> {
     fn lerp(&self, other: &Self, t: F) -> Self {
-        let mut result = *self;
-        for i in 0..N {
-            result[i] = Float::lerp(self[i], other[i], t);
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.lerp(b, t)),
+            _ => step(self, other, t),
         }
-        result
+    }
+}
+
+/// Wrapper for values that snap from `self` to `other` at `t = 0.5` instead of blending.
+///
+/// Useful for enums, sprite indices, or any `Clone` type without a meaningful midpoint.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Discrete<T>(pub T);
+
+impl<F: Float, T: Clone> Lerp<F> for Discrete<T> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        step(self, other, t)
     }
 }
 
@@ -131,9 +333,33 @@ impl<F: Float> Lerp<F> for Angle<F> {
     }
 }
 
+/// Spherical interpolation of `[x, y, z, w]` unit quaternions along the shortest arc.
+#[cfg(any(feature = "mint", feature = "gltf"))]
+pub(crate) fn slerp_xyzw<F: Float>(a: [F; 4], b: [F; 4], t: F) -> [F; 4] {
+    let mut b = b;
+    let mut dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+    if dot < F::zero() {
+        b = [-b[0], -b[1], -b[2], -b[3]];
+        dot = -dot;
+    }
+
+    // Nearly parallel: fall back to normalized lerp to avoid dividing by sin(~0).
+    if dot > F::from_f32(0.9995) {
+        let q: [F; 4] = core::array::from_fn(|i| Float::lerp(a[i], b[i], t));
+        let len = (q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]).sqrt();
+        return q.map(|c| c / len);
+    }
+
+    let theta = dot.min(F::one()).acos();
+    let sin_theta = theta.sin();
+    let wa = ((F::one() - t) * theta).sin() / sin_theta;
+    let wb = (t * theta).sin() / sin_theta;
+    core::array::from_fn(|i| a[i] * wa + b[i] * wb)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Angle, Lerp, Rgba};
+    use super::{Angle, Discrete, Lerp, Rgba, Rounded, Rounding};
 
     const EPS: f32 = 1e-5;
 
@@ -226,4 +452,93 @@ mod tests {
         let mid = a.lerp(&b, 0.5).to_degrees();
         assert!(approx(mid, 90.0));
     }
+
+    #[test]
+    fn lerp_integer_rounds_nearest() {
+        assert_eq!(0i32.lerp(&10, 0.25f32), 3);
+        assert_eq!(0i32.lerp(&10, 0.24f32), 2);
+        assert_eq!(0i32.lerp(&-10, 0.25f32), -3);
+        assert_eq!(200u8.lerp(&0, 0.5f32), 100);
+        assert_eq!(0u8.lerp(&255, 1.5f32), 255);
+    }
+
+    #[test]
+    fn lerp_wide_integers_stay_exact() {
+        assert_eq!((i64::MAX - 1).lerp(&0, 0.0f32), i64::MAX - 1);
+        assert_eq!(0u64.lerp(&(u64::MAX - 7), 1.0f32), u64::MAX - 7);
+        assert_eq!((1i64 << 60).lerp(&((1 << 60) + 5), 0.5f64), (1 << 60) + 3);
+        assert_eq!(u128::MAX.lerp(&(u128::MAX - 10), 0.5f32), u128::MAX - 5);
+        assert_eq!(i128::MIN.lerp(&(i128::MIN + 3), 0.5f32), i128::MIN + 1);
+        assert_eq!((-3isize).lerp(&0, 0.5f32), -2);
+        assert_eq!(0usize.lerp(&10, 1.5f32), 15);
+
+        let floor = Rounded::new(i128::MAX - 3, Rounding::Floor);
+        let to = Rounded::new(i128::MAX, Rounding::Floor);
+        assert_eq!(floor.lerp(&to, 0.5f32).value, i128::MAX - 2);
+        let trunc = Rounded::new(-1i64 << 62, Rounding::Trunc);
+        let to = Rounded::new((-1i64 << 62) - 10, Rounding::Trunc);
+        assert_eq!(trunc.lerp(&to, 0.55f64).value, (-1i64 << 62) - 5);
+    }
+
+    #[test]
+    fn lerp_rounded_modes() {
+        let floor = Rounded::new(0i64, Rounding::Floor);
+        let ceil = Rounded::new(0i64, Rounding::Ceil);
+        let trunc = Rounded::new(0i64, Rounding::Trunc);
+        assert_eq!(
+            floor
+                .lerp(&Rounded::new(10, Rounding::Floor), 0.99f64)
+                .value,
+            9
+        );
+        assert_eq!(
+            ceil.lerp(&Rounded::new(10, Rounding::Ceil), 0.01f64).value,
+            1
+        );
+        assert_eq!(
+            trunc
+                .lerp(&Rounded::new(-10, Rounding::Trunc), 0.55f64)
+                .value,
+            -5
+        );
+    }
+
+    #[test]
+    fn lerp_bool_and_char_step() {
+        assert!(!false.lerp(&true, 0.49f32));
+        assert!(false.lerp(&true, 0.5f32));
+        assert_eq!('a'.lerp(&'z', 0.2f32), 'a');
+        assert_eq!('a'.lerp(&'z', 0.8f32), 'z');
+    }
+
+    #[test]
+    fn lerp_option() {
+        assert_eq!(Some(0.0f32).lerp(&Some(10.0), 0.5), Some(5.0));
+        assert_eq!(Some(1.0f32).lerp(&None, 0.25), Some(1.0));
+        assert_eq!(Some(1.0f32).lerp(&None, 0.75), None);
+    }
+
+    #[test]
+    fn lerp_discrete() {
+        #[derive(Clone, Debug, PartialEq)]
+        enum Pose {
+            Idle,
+            Jump,
+        }
+        let a = Discrete(Pose::Idle);
+        let b = Discrete(Pose::Jump);
+        assert_eq!(a.lerp(&b, 0.3f32).0, Pose::Idle);
+        assert_eq!(a.lerp(&b, 0.6f32).0, Pose::Jump);
+    }
+
+    #[test]
+    fn lerp_heterogeneous_tuple() {
+        let a = (0.0f32, 0i32, false, Rgba::new(0.0f32, 0.0, 0.0, 1.0));
+        let b = (10.0f32, 100i32, true, Rgba::new(1.0f32, 1.0, 1.0, 1.0));
+        let mid = a.lerp(&b, 0.5);
+        assert!(approx(mid.0, 5.0));
+        assert_eq!(mid.1, 50);
+        assert!(mid.2);
+        assert!(approx(mid.3.g, 0.5));
+    }
 }
@@ -1,28 +1,79 @@
 #![no_std]
+#[cfg(any(feature = "alloc", test))]
 extern crate alloc;
 
+pub mod animation;
+#[cfg(feature = "alloc")]
+pub mod animator;
+#[cfg(feature = "alloc")]
+pub mod bake;
+#[cfg(feature = "alloc")]
+pub mod clip;
+pub mod clock;
 pub mod config;
 pub mod easing;
 pub mod error;
+pub mod fixed;
 pub mod float;
+pub mod group;
+pub mod import;
+mod interop;
+#[cfg(feature = "alloc")]
 pub mod keyframes;
 pub mod lerp;
 pub mod loop_mode;
+#[cfg(feature = "alloc")]
+pub mod marker;
 pub mod observer;
+mod rng;
+#[cfg(feature = "alloc")]
+mod slots;
+#[cfg(feature = "alloc")]
+pub mod spline;
 pub mod spring;
+#[cfg(feature = "alloc")]
+pub mod stagger;
 pub mod state;
+pub mod text;
+#[cfg(feature = "alloc")]
 pub mod timeline;
 pub mod tween;
+pub mod vector;
 
+pub use animation::Animation;
+#[cfg(feature = "alloc")]
+pub use animator::{Animator, FinishPolicy};
+#[cfg(feature = "alloc")]
+pub use bake::{bake, simplify};
+#[cfg(feature = "alloc")]
+pub use clip::{AnimationClip, TrackTarget};
+pub use clock::Clock;
 pub use config::TweenConfig;
 pub use easing::Easing;
 pub use error::TweenError;
+pub use fixed::{FixedParallel, FixedSequence, FixedStagger};
 pub use float::Float;
-pub use keyframes::{Keyframe, Keyframes};
-pub use lerp::{Angle, Lerp, Rgba};
+pub use group::{AnimationTuple, Group};
+#[cfg(feature = "alloc")]
+pub use keyframes::{BezierHandle, Interpolation, Keyframe, Keyframes};
+pub use lerp::{Angle, Discrete, Lerp, Rgba, Rounded, Rounding};
 pub use loop_mode::{LoopMode, PlayDirection};
+#[cfg(feature = "alloc")]
+pub use marker::{Marker, SeekEvents};
 pub use observer::{NoOpObserver, TweenObserver};
+#[cfg(feature = "alloc")]
+pub use spline::{KeySpline, Spline};
 pub use spring::{SpringConfig, SpringTween};
+#[cfg(feature = "alloc")]
+pub use stagger::{GridAxis, StaggerFrom, StaggerPattern, StaggerSpread};
 pub use state::TweenState;
-pub use timeline::{Timeline, TimelineEntry};
-pub use tween::{Parallel, Sequence, Stagger, Tween, TweenId};
+pub use text::{Counter, FixedString, NumberFormat, RevealUnit, Scramble, Typewriter};
+#[cfg(feature = "alloc")]
+pub use timeline::{
+    ActiveLeaf, AnimationTimeline, BlendMode, BlendedEntry, NestedTimeline, Timeline,
+    TimelineEntry, TimelinePosition,
+};
+#[cfg(feature = "alloc")]
+pub use tween::{Parallel, Sequence, Stagger};
+pub use tween::{Tween, TweenId};
+pub use vector::VectorSpace;
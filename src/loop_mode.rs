@@ -21,3 +21,22 @@ pub enum PlayDirection {
     Forward,
     Backward,
 }
+
+impl PlayDirection {
+    /// The opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+        }
+    }
+
+    /// Tick within a pass of `total` ticks at which a child lasting `duration` ticks, and
+    /// starting at `start` in a forward pass, starts when playing in this direction.
+    pub(crate) fn pass_start(self, start: u32, duration: u32, total: u32) -> u32 {
+        match self {
+            Self::Forward => start,
+            Self::Backward => total.saturating_sub(start.saturating_add(duration)),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+
+/// A user event placed at a tick on a [`Timeline`](crate::Timeline) or
+/// [`Keyframes`](crate::Keyframes) track, reported when playback crosses it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Marker {
+    pub tick: u32,
+    /// Caller-chosen id, e.g. an index into a table of sounds.
+    pub event: u32,
+}
+
+impl Marker {
+    pub fn new(tick: u32, event: u32) -> Self {
+        Self { tick, event }
+    }
+}
+
+/// What a seek does with the markers between the old and new playhead.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum SeekEvents {
+    /// Jump silently; markers at the landing tick count as already passed.
+    #[default]
+    Suppress,
+    /// Fire every marker crossed, in the order the jump passes them, including the landing tick.
+    Fire,
+}
+
+/// Markers of one track and the ones crossed by the latest step.
+///
+/// A step from `from` to `to` fires markers after `from` up to and including `to`. The tick a
+/// leg starts at fires on its first step, so markers at tick 0 fire on every loop iteration,
+/// while a ping-pong turn does not fire the turning tick a second time.
+#[derive(Clone, Debug)]
+pub(crate) struct MarkerTrack {
+    /// Sorted by tick, in insertion order within a tick.
+    markers: Vec<Marker>,
+    fired: Vec<Marker>,
+    seek_events: SeekEvents,
+    /// The playhead is at the start of a leg whose markers have not fired yet.
+    fresh: bool,
+    /// Steps add to `fired` instead of replacing it, for a multi-tick step.
+    held: bool,
+}
+
+impl MarkerTrack {
+    pub(crate) fn new() -> Self {
+        Self {
+            markers: Vec::new(),
+            fired: Vec::new(),
+            seek_events: SeekEvents::Suppress,
+            fresh: true,
+            held: false,
+        }
+    }
+
+    pub(crate) fn add(&mut self, marker: Marker) {
+        let index = self.markers.partition_point(|m| m.tick <= marker.tick);
+        self.markers.insert(index, marker);
+    }
+
+    pub(crate) fn markers(&self) -> &[Marker] {
+        &self.markers
+    }
+
+    pub(crate) fn fired(&self) -> &[Marker] {
+        &self.fired
+    }
+
+    pub(crate) fn set_seek_events(&mut self, seek_events: SeekEvents) {
+        self.seek_events = seek_events;
+    }
+
+    /// Forget the previous step's events, e.g. when a tick does not move the playhead.
+    pub(crate) fn clear(&mut self) {
+        if !self.held {
+            self.fired.clear();
+        }
+    }
+
+    /// Record the markers crossed by playback moving from `from` to `to`.
+    pub(crate) fn step(&mut self, from: u32, to: u32) {
+        self.clear();
+        self.cross(from, to);
+    }
+
+    /// Start a step of several ticks: until [`release`](Self::release), each tick's markers
+    /// are added to the ones already fired.
+    pub(crate) fn hold(&mut self) {
+        self.fired.clear();
+        self.held = true;
+    }
+
+    pub(crate) fn release(&mut self) {
+        self.held = false;
+    }
+
+    /// Record the markers crossed by a seek, if seeks fire events.
+    pub(crate) fn seek(&mut self, from: u32, to: u32) {
+        self.fired.clear();
+        match self.seek_events {
+            SeekEvents::Fire => self.cross(from, to),
+            SeekEvents::Suppress => self.fresh = false,
+        }
+    }
+
+    /// Move every marker to `scale(tick)`. `scale` must not decrease, so the order holds.
+    pub(crate) fn retime(&mut self, scale: impl Fn(u32) -> u32) {
+        for marker in &mut self.markers {
+            marker.tick = scale(marker.tick);
+        }
+    }
+
+    /// The playhead is at the start of a new leg or iteration.
+    pub(crate) fn restart(&mut self) {
+        self.fresh = true;
+    }
+
+    fn cross(&mut self, from: u32, to: u32) {
+        let inclusive = core::mem::replace(&mut self.fresh, false);
+        let passed = |tick: u32| tick != from || inclusive;
+        if from <= to {
+            let crossed = self
+                .markers
+                .iter()
+                .filter(|m| m.tick >= from && m.tick <= to && passed(m.tick));
+            self.fired.extend(crossed);
+        } else {
+            let crossed = self
+                .markers
+                .iter()
+                .rev()
+                .filter(|m| m.tick <= from && m.tick >= to && passed(m.tick));
+            self.fired.extend(crossed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Marker, MarkerTrack, SeekEvents};
+
+    fn track() -> MarkerTrack {
+        let mut track = MarkerTrack::new();
+        for (tick, event) in [(5, 2), (0, 0), (5, 3), (10, 4), (2, 1)] {
+            track.add(Marker::new(tick, event));
+        }
+        track
+    }
+
+    fn events(track: &MarkerTrack) -> alloc::vec::Vec<u32> {
+        track.fired().iter().map(|m| m.event).collect()
+    }
+
+    #[test]
+    fn markers_fire_once_per_crossing() {
+        let mut track = track();
+        track.step(0, 1);
+        assert_eq!(events(&track), [0]);
+        track.step(1, 2);
+        assert_eq!(events(&track), [1]);
+        track.step(2, 10);
+        assert_eq!(events(&track), [2, 3, 4]);
+        track.step(10, 4);
+        assert_eq!(events(&track), [3, 2]);
+        track.step(4, 4);
+        assert!(events(&track).is_empty());
+
+        track.restart();
+        track.step(10, 9);
+        assert_eq!(events(&track), [4]);
+
+        track.hold();
+        track.step(0, 2);
+        track.clear();
+        track.step(2, 5);
+        track.release();
+        assert_eq!(events(&track), [1, 2, 3]);
+        track.step(5, 6);
+        assert!(events(&track).is_empty());
+    }
+
+    #[test]
+    fn seek_fires_or_suppresses() {
+        let mut track = track();
+        track.seek(0, 6);
+        assert!(events(&track).is_empty());
+        track.step(6, 7);
+        assert!(events(&track).is_empty());
+
+        track.set_seek_events(SeekEvents::Fire);
+        track.seek(7, 1);
+        assert_eq!(events(&track), [3, 2, 1]);
+    }
+}
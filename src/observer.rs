@@ -21,10 +21,10 @@ mod tests {
     #[test]
     fn observer_noop_compiles() {
         let mut observer = NoOpObserver;
-        observer.on_start(TweenId(1));
-        observer.on_pause(TweenId(1));
-        observer.on_resume(TweenId(1));
-        observer.on_loop(TweenId(1), 2);
-        observer.on_complete(TweenId(1));
+        observer.on_start(TweenId::new(1, 0));
+        observer.on_pause(TweenId::new(1, 0));
+        observer.on_resume(TweenId::new(1, 0));
+        observer.on_loop(TweenId::new(1, 0), 2);
+        observer.on_complete(TweenId::new(1, 0));
     }
 }
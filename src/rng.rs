@@ -0,0 +1,14 @@
+/// SplitMix64 finalizer: a stateless, deterministic 64-bit hash.
+///
+/// Used wherever an effect needs reproducible pseudo-randomness from a user seed.
+pub(crate) fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Hash `seed` together with `value`.
+pub(crate) fn mix(seed: u64, value: u64) -> u64 {
+    splitmix64(seed ^ splitmix64(value))
+}
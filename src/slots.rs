@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use crate::tween::TweenId;
+
+/// Where an id's entry lives; the generation counts removals from the slot.
+#[derive(Clone, Debug)]
+struct Slot {
+    generation: u32,
+    /// Index into the owner's storage, or `None` while the slot is free.
+    position: Option<usize>,
+}
+
+/// Generational ids for entries stored densely elsewhere, e.g. in a `Vec`.
+///
+/// Each id maps to its entry's position in the owner's storage. Removing an entry bumps its
+/// slot's generation, so the id and any copies of it stop resolving even once the slot is
+/// reused. The owner reports moves with [`moved`](Self::moved) or
+/// [`close_gap`](Self::close_gap).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Slots {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+}
+
+impl Slots {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issue an id for an entry stored at `position`, reusing a freed slot if there is one.
+    pub(crate) fn insert(&mut self, position: usize) -> TweenId {
+        let position = Some(position);
+        match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.position = position;
+                TweenId::new(index, slot.generation)
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    position,
+                });
+                TweenId::new(self.slots.len() as u32 - 1, 0)
+            }
+        }
+    }
+
+    /// Position of the entry behind `id`, unless it has been removed.
+    pub(crate) fn get(&self, id: TweenId) -> Option<usize> {
+        self.slots
+            .get(id.index() as usize)
+            .filter(|slot| slot.generation == id.generation())
+            .and_then(|slot| slot.position)
+    }
+
+    /// Retire `id` and free its slot. Returns the position its entry was at.
+    pub(crate) fn remove(&mut self, id: TweenId) -> Option<usize> {
+        let position = self.get(id)?;
+        let slot = &mut self.slots[id.index() as usize];
+        slot.position = None;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(id.index());
+        Some(position)
+    }
+
+    /// The entry behind `id` now lives at `position`, e.g. after a swap-remove.
+    pub(crate) fn moved(&mut self, id: TweenId, position: usize) {
+        if let Some(slot) = self.slots.get_mut(id.index() as usize) {
+            slot.position = Some(position);
+        }
+    }
+
+    /// The entry at `position` was removed and every later entry shifted down by one.
+    pub(crate) fn close_gap(&mut self, position: usize) {
+        for slot in &mut self.slots {
+            match &mut slot.position {
+                Some(later) if *later > position => *later -= 1,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slots;
+
+    #[test]
+    fn slots_retire_ids_and_track_moves() {
+        let mut slots = Slots::new();
+        let a = slots.insert(0);
+        let b = slots.insert(1);
+        let c = slots.insert(2);
+
+        assert_eq!(slots.remove(a), Some(0));
+        assert_eq!(slots.remove(a), None);
+        slots.close_gap(0);
+        assert_eq!((slots.get(b), slots.get(c)), (Some(0), Some(1)));
+
+        let reused = slots.insert(2);
+        assert_eq!(reused.index(), a.index());
+        assert_eq!(slots.get(a), None);
+        assert_eq!(slots.get(reused), Some(2));
+
+        slots.moved(reused, 0);
+        assert_eq!(slots.get(reused), Some(0));
+    }
+}
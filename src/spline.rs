@@ -0,0 +1,381 @@
+use crate::float::Float;
+use crate::keyframes::Keyframe;
+use crate::lerp::Lerp;
+use crate::vector::VectorSpace;
+
+/// Cubic interpolation mode for the segment leaving a keyframe.
+///
+/// Spline segments pass through every keyframe with continuous velocity. The tangent at each
+/// end of a segment comes from that keyframe's own mode, falling back to the starting
+/// keyframe's mode when the end keyframe has none. Tangents are in value units per tick.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Spline<T, F> {
+    /// Tangents from the neighbouring keyframes, weighted by their tick spacing.
+    CatmullRom,
+    /// Explicit tangents arriving at (`in_tangent`) and leaving (`out_tangent`) the keyframe.
+    Hermite { in_tangent: T, out_tangent: T },
+    /// TCB spline. All zero is Catmull-Rom; tension 1 gives zero tangents, continuity
+    /// sharpens corners, bias pushes the curve toward the previous (+) or next (-) keyframe.
+    KochanekBartels { tension: F, continuity: F, bias: F },
+    /// Catmull-Rom tangents limited so the curve never overshoots between keyframes.
+    Monotone,
+}
+
+/// A keyframe's [`Spline`] mode, carrying the vector arithmetic needed to evaluate it.
+///
+/// `Keyframe` holds values that may have no vector arithmetic (text, colours, booleans), so the
+/// arithmetic is captured here, where `T: VectorSpace` is known. Build one with
+/// [`KeySpline::new`] or `Spline::CatmullRom.into()`.
+#[derive(Clone, Debug)]
+pub struct KeySpline<T: Lerp<F>, F: Float> {
+    pub mode: Spline<T, F>,
+    segment: fn(&[Keyframe<T, F>], usize, F) -> T,
+    scale: fn(&T, F) -> T,
+}
+
+impl<T: Lerp<F> + VectorSpace<F>, F: Float> KeySpline<T, F> {
+    pub fn new(mode: Spline<T, F>) -> Self {
+        Self {
+            mode,
+            segment: cubic_segment::<T, F>,
+            scale: T::scaled,
+        }
+    }
+}
+
+impl<T: Lerp<F> + VectorSpace<F>, F: Float> From<Spline<T, F>> for KeySpline<T, F> {
+    fn from(mode: Spline<T, F>) -> Self {
+        Self::new(mode)
+    }
+}
+
+impl<T: Lerp<F>, F: Float> KeySpline<T, F> {
+    /// Evaluate the segment from `frames[i]` to `frames[i + 1]` at parameter `s`.
+    pub(crate) fn segment(&self, frames: &[Keyframe<T, F>], i: usize, s: F) -> T {
+        (self.segment)(frames, i, s)
+    }
+
+    /// Keep explicit tangents matching after the track's ticks are stretched by `factor`.
+    pub(crate) fn retime(&mut self, factor: F) {
+        if let Spline::Hermite {
+            in_tangent,
+            out_tangent,
+        } = &mut self.mode
+        {
+            *in_tangent = (self.scale)(in_tangent, F::one() / factor);
+            *out_tangent = (self.scale)(out_tangent, F::one() / factor);
+        }
+    }
+}
+
+/// Which side of a keyframe a tangent is for.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Side {
+    Incoming,
+    Outgoing,
+}
+
+/// Evaluate the cubic segment from `frames[i]` to `frames[i + 1]` at parameter `s`.
+pub(crate) fn cubic_segment<T, F>(frames: &[Keyframe<T, F>], i: usize, s: F) -> T
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let a = &frames[i];
+    let b = &frames[i + 1];
+    let Some(start_mode) = a.spline() else {
+        return a.value.lerp(&b.value, s);
+    };
+    let end_mode = b.spline().unwrap_or(start_mode);
+    let duration = F::from_f32(b.tick.saturating_sub(a.tick) as f32);
+
+    let m0 = tangent(frames, i, start_mode, Side::Outgoing).scaled(duration);
+    let m1 = tangent(frames, i + 1, end_mode, Side::Incoming).scaled(duration);
+    hermite(&a.value, &m0, &b.value, &m1, s)
+}
+
+/// Cubic Hermite basis evaluation with tangents in segment-parameter units.
+pub(crate) fn hermite<T: VectorSpace<F>, F: Float>(p0: &T, m0: &T, p1: &T, m1: &T, s: F) -> T {
+    let s2 = s * s;
+    let s3 = s2 * s;
+    let two = F::two();
+    let three = F::from_f32(3.0);
+    let h00 = two * s3 - three * s2 + F::one();
+    let h10 = s3 - two * s2 + s;
+    let h01 = three * s2 - two * s3;
+    let h11 = s3 - s2;
+    p0.scaled(h00)
+        .plus(&m0.scaled(h10))
+        .plus(&p1.scaled(h01))
+        .plus(&m1.scaled(h11))
+}
+
+/// Tangent at `frames[k]` in value units per tick.
+fn tangent<T, F>(frames: &[Keyframe<T, F>], k: usize, mode: &Spline<T, F>, side: Side) -> T
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    match mode {
+        Spline::CatmullRom => catmull_rom_tangent(frames, k),
+        Spline::Hermite {
+            in_tangent,
+            out_tangent,
+        } => match side {
+            Side::Incoming => in_tangent.clone(),
+            Side::Outgoing => out_tangent.clone(),
+        },
+        Spline::KochanekBartels {
+            tension,
+            continuity,
+            bias,
+        } => kochanek_bartels_tangent(frames, k, *tension, *continuity, *bias, side),
+        Spline::Monotone => monotone_tangent(frames, k),
+    }
+}
+
+/// Value change per tick from `frames[from]` to `frames[to]`, zero for coincident ticks.
+fn slope<T, F>(frames: &[Keyframe<T, F>], from: usize, to: usize) -> T
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let dt = frames[to].tick.saturating_sub(frames[from].tick);
+    if dt == 0 {
+        return T::zero();
+    }
+    frames[to]
+        .value
+        .minus(&frames[from].value)
+        .scaled(F::one() / F::from_f32(dt as f32))
+}
+
+fn catmull_rom_tangent<T, F>(frames: &[Keyframe<T, F>], k: usize) -> T
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let prev = k.saturating_sub(1);
+    let next = (k + 1).min(frames.len() - 1);
+    slope(frames, prev, next)
+}
+
+fn kochanek_bartels_tangent<T, F>(
+    frames: &[Keyframe<T, F>],
+    k: usize,
+    tension: F,
+    continuity: F,
+    bias: F,
+    side: Side,
+) -> T
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let last = frames.len() - 1;
+    let has_prev = k > 0;
+    let has_next = k < last;
+    let d_in = if has_prev {
+        frames[k].value.minus(&frames[k - 1].value)
+    } else {
+        frames[k + 1].value.minus(&frames[k].value)
+    };
+    let d_out = if has_next {
+        frames[k + 1].value.minus(&frames[k].value)
+    } else {
+        frames[k].value.minus(&frames[k - 1].value)
+    };
+    let dt_in = if has_prev {
+        frames[k].tick - frames[k - 1].tick
+    } else {
+        frames[k + 1].tick - frames[k].tick
+    };
+    let dt_out = if has_next {
+        frames[k + 1].tick - frames[k].tick
+    } else {
+        frames[k].tick - frames[k - 1].tick
+    };
+
+    let one = F::one();
+    let half = F::half();
+    let (w_in, w_out, own_dt) = match side {
+        Side::Incoming => (
+            (one - tension) * (one - continuity) * (one + bias) * half,
+            (one - tension) * (one + continuity) * (one - bias) * half,
+            dt_in,
+        ),
+        Side::Outgoing => (
+            (one - tension) * (one + continuity) * (one + bias) * half,
+            (one - tension) * (one - continuity) * (one - bias) * half,
+            dt_out,
+        ),
+    };
+    let span = dt_in + dt_out;
+    if span == 0 || own_dt == 0 {
+        return T::zero();
+    }
+
+    // Tangent per unit of the adjacent segment, rescaled for uneven keyframe spacing and
+    // then converted to per-tick units.
+    let per_segment = d_in.scaled(w_in).plus(&d_out.scaled(w_out));
+    let spacing = F::two() * F::from_f32(own_dt as f32) / F::from_f32(span as f32);
+    per_segment.scaled(spacing / F::from_f32(own_dt as f32))
+}
+
+fn monotone_tangent<T, F>(frames: &[Keyframe<T, F>], k: usize) -> T
+where
+    T: Lerp<F> + VectorSpace<F>,
+    F: Float,
+{
+    let last = frames.len() - 1;
+    if k == 0 || k == last {
+        let (from, to) = if k == 0 { (0, 1) } else { (last - 1, last) };
+        return slope(frames, from, to);
+    }
+
+    let before = slope(frames, k - 1, k);
+    let after = slope(frames, k, k + 1);
+    if before.dot(&after) <= F::zero() {
+        return T::zero();
+    }
+
+    // Hyman filter: |m| <= 3 * min(|secant|) keeps each segment monotone.
+    let m = catmull_rom_tangent(frames, k);
+    let limit = F::from_f32(3.0) * before.length().min(after.length());
+    let len = m.length();
+    if len > limit {
+        m.scaled(limit / len)
+    } else {
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::Spline;
+    use crate::keyframes::{Keyframe, Keyframes};
+
+    const EPS: f32 = 1e-3;
+
+    fn samples(keyframes: &mut Keyframes<f32, f32>) -> Vec<f32> {
+        let mut out = vec![keyframes.value()];
+        while !keyframes.is_finished() {
+            out.push(keyframes.tick());
+        }
+        out
+    }
+
+    fn keys(values: &[(f32, u32)], spline: Spline<f32, f32>) -> Keyframes<f32, f32> {
+        Keyframes::new(
+            values
+                .iter()
+                .map(|&(v, t)| Keyframe::new(v, t).with_spline(spline.clone()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn catmull_rom_smooth_through_keys() {
+        let mut track = keys(&[(0.0, 0), (100.0, 50), (40.0, 100)], Spline::CatmullRom);
+        let v = samples(&mut track);
+        assert!((v[50] - 100.0).abs() < EPS);
+        assert!((v[100] - 40.0).abs() < EPS);
+        let left = v[50] - v[49];
+        let right = v[51] - v[50];
+        assert!((left - right).abs() < 0.2);
+    }
+
+    #[test]
+    fn hermite_explicit_tangents() {
+        let flat = Spline::Hermite {
+            in_tangent: 0.0f32,
+            out_tangent: 0.0,
+        };
+        let mut track = keys(&[(0.0, 0), (10.0, 10)], flat);
+        let v = samples(&mut track);
+        assert!((v[5] - 5.0).abs() < EPS);
+        assert!((v[2] - 1.04).abs() < EPS);
+
+        let steep = Spline::Hermite {
+            in_tangent: 0.0f32,
+            out_tangent: 3.0,
+        };
+        let mut track = keys(&[(0.0, 0), (10.0, 10)], steep);
+        let v = samples(&mut track);
+        assert!(v[1] > 2.0);
+    }
+
+    #[test]
+    fn kochanek_bartels_tension() {
+        let tight = Spline::KochanekBartels {
+            tension: 1.0f32,
+            continuity: 0.0,
+            bias: 0.0,
+        };
+        let loose = Spline::KochanekBartels {
+            tension: 0.0f32,
+            continuity: 0.0,
+            bias: 0.0,
+        };
+        let points = [(0.0, 0), (10.0, 10), (20.0, 20)];
+        let tight = samples(&mut keys(&points, tight));
+        let loose = samples(&mut keys(&points, loose));
+        let catmull = samples(&mut keys(&points, Spline::CatmullRom));
+        // Zero tension matches Catmull-Rom; full tension stalls at each key.
+        for (a, b) in loose.iter().zip(catmull.iter()) {
+            assert!((a - b).abs() < EPS);
+        }
+        assert!((tight[10] - tight[9]) < (loose[10] - loose[9]));
+    }
+
+    #[test]
+    fn monotone_never_overshoots() {
+        let points = [(0.0, 0), (0.0, 10), (10.0, 20), (10.0, 30)];
+        let catmull = samples(&mut keys(&points, Spline::CatmullRom));
+        assert!(catmull.iter().any(|&v| !(-EPS..=10.0 + EPS).contains(&v)));
+        let monotone = samples(&mut keys(&points, Spline::Monotone));
+        assert!(monotone.iter().all(|&v| (-EPS..=10.0 + EPS).contains(&v)));
+        assert!(monotone.windows(2).all(|w| w[1] >= w[0] - EPS));
+    }
+
+    #[test]
+    fn spline_key_from_struct_literal() {
+        let key = |value, tick| Keyframe {
+            spline: Some(Spline::CatmullRom.into()),
+            ..Keyframe::new(value, tick)
+        };
+        let literal = samples(&mut Keyframes::new(vec![
+            key(0.0f32, 0),
+            key(100.0, 50),
+            key(40.0, 100),
+        ]));
+        let built = samples(&mut keys(
+            &[(0.0, 0), (100.0, 50), (40.0, 100)],
+            Spline::CatmullRom,
+        ));
+        assert_eq!(literal, built);
+        assert_eq!(
+            Keyframe::new(0.0f32, 0)
+                .with_spline(Spline::Monotone)
+                .spline(),
+            Some(&Spline::Monotone)
+        );
+    }
+
+    #[test]
+    fn spline_vector_values() {
+        let mut track = Keyframes::new(vec![
+            Keyframe::new((0.0f32, 0.0f32), 0).with_spline(Spline::CatmullRom),
+            Keyframe::new((10.0, 10.0), 10).with_spline(Spline::CatmullRom),
+            Keyframe::new((20.0, 0.0), 20).with_spline(Spline::CatmullRom),
+        ]);
+        for _ in 0..10 {
+            track.tick();
+        }
+        let (x, y) = track.value();
+        assert!((x - 10.0).abs() < EPS && (y - 10.0).abs() < EPS);
+    }
+}
@@ -1,4 +1,11 @@
+#[cfg(feature = "alloc")]
+use crate::bake::record;
 use crate::float::Float;
+#[cfg(feature = "alloc")]
+use crate::keyframes::Keyframes;
+#[cfg(feature = "alloc")]
+use crate::lerp::Lerp;
+use crate::vector::VectorSpace;
 
 /// Configuration for a spring-based tween.
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -61,20 +68,26 @@ impl<F: Float> SpringConfig<F> {
 }
 
 /// Physics-based spring animation with retargetable target.
+///
+/// Springs scalars by default; any [`VectorSpace`] type (tuples, arrays, `glam`/`mint`/`nalgebra`
+/// vectors) can be sprung as a whole, settling once both velocity and displacement lengths fall
+/// below the rest threshold.
 #[derive(Clone, Debug)]
-pub struct SpringTween<F: Float> {
-    value: F,
-    velocity: F,
-    target: F,
+pub struct SpringTween<F: Float, T: VectorSpace<F> = F> {
+    initial: T,
+    value: T,
+    velocity: T,
+    target: T,
     config: SpringConfig<F>,
     at_rest: bool,
 }
 
-impl<F: Float> SpringTween<F> {
-    pub fn new(initial: F, target: F, config: SpringConfig<F>) -> Self {
+impl<F: Float, T: VectorSpace<F>> SpringTween<F, T> {
+    pub fn new(initial: T, target: T, config: SpringConfig<F>) -> Self {
         Self {
+            initial: initial.clone(),
             value: initial,
-            velocity: F::zero(),
+            velocity: T::zero(),
             target,
             config,
             at_rest: false,
@@ -82,57 +95,91 @@ impl<F: Float> SpringTween<F> {
     }
 
     /// Advance by one tick and return current value.
-    pub fn tick(&mut self) -> F {
+    pub fn tick(&mut self) -> T {
         if self.at_rest {
-            return self.value;
+            return self.value.clone();
         }
 
         // One animation tick uses a fixed timestep to keep spring constants practical.
         let dt = F::from_f32(1.0 / 60.0);
-        let displacement = self.value - self.target;
-        let force = -self.config.stiffness * displacement - self.config.damping * self.velocity;
-        let acceleration = force / self.config.mass;
+        let displacement = self.value.minus(&self.target);
+        let force = displacement
+            .scaled(-self.config.stiffness)
+            .minus(&self.velocity.scaled(self.config.damping));
+        let acceleration = force.scaled(F::one() / self.config.mass);
 
-        self.velocity = self.velocity + acceleration * dt;
-        self.value = self.value + self.velocity * dt;
+        self.velocity = self.velocity.plus(&acceleration.scaled(dt));
+        self.value = self.value.plus(&self.velocity.scaled(dt));
 
-        let displacement_after = self.value - self.target;
-        if self.velocity.abs() < self.config.rest_threshold
-            && displacement_after.abs() < self.config.rest_threshold
+        let displacement_after = self.value.minus(&self.target);
+        if self.velocity.length() < self.config.rest_threshold
+            && displacement_after.length() < self.config.rest_threshold
         {
-            self.value = self.target;
-            self.velocity = F::zero();
+            self.value = self.target.clone();
+            self.velocity = T::zero();
             self.at_rest = true;
         }
 
-        self.value
+        self.value.clone()
     }
 
-    pub fn value(&self) -> F {
-        self.value
+    pub fn value(&self) -> T {
+        self.value.clone()
     }
 
-    pub fn velocity(&self) -> F {
-        self.velocity
+    pub fn velocity(&self) -> T {
+        self.velocity.clone()
     }
 
     pub fn is_at_rest(&self) -> bool {
         self.at_rest
     }
 
+    /// Fraction [0, 1] of the distance from the starting value to the target covered so far;
+    /// 1 once at rest.
+    pub fn progress(&self) -> F {
+        let span = self.target.minus(&self.initial).length();
+        if self.at_rest || span == F::zero() {
+            return F::one();
+        }
+        let remaining = self.value.minus(&self.target).length() / span;
+        (F::one() - remaining).clamp(F::zero(), F::one())
+    }
+
     /// Change target mid-flight and wake if resting.
-    pub fn set_target(&mut self, new_target: F) {
+    pub fn set_target(&mut self, new_target: T) {
         self.target = new_target;
         self.at_rest = false;
     }
 
+    /// Bake from the current state until the spring comes to rest or `max_ticks` pass,
+    /// keeping every `interval`-th tick.
+    #[cfg(feature = "alloc")]
+    pub fn bake(&self, max_ticks: u32, interval: u32) -> Keyframes<T, F>
+    where
+        T: Lerp<F>,
+    {
+        let mut spring = self.clone();
+        record(spring.value(), interval, max_ticks, || {
+            (spring.tick(), spring.is_at_rest())
+        })
+    }
+
     /// Immediately set value and velocity.
-    pub fn reset(&mut self, value: F, target: F) {
+    pub fn reset(&mut self, value: T, target: T) {
+        self.initial = value.clone();
         self.value = value;
-        self.velocity = F::zero();
+        self.velocity = T::zero();
         self.target = target;
         self.at_rest = false;
     }
+
+    /// Start again from the last starting value with zero velocity, toward the current target.
+    pub fn restart(&mut self) {
+        self.value = self.initial.clone();
+        self.velocity = T::zero();
+        self.at_rest = false;
+    }
 }
 
 #[cfg(test)]
@@ -229,4 +276,17 @@ mod tests {
             assert!((va - vb).abs() < 1e-6);
         }
     }
+
+    #[test]
+    fn spring_vector_reaches_target() {
+        let mut spring = SpringTween::new((0.0f32, 0.0f32), (30.0, -40.0), SpringConfig::stiff());
+        for _ in 0..600 {
+            spring.tick();
+            if spring.is_at_rest() {
+                break;
+            }
+        }
+        assert!(spring.is_at_rest());
+        assert_eq!(spring.value(), (30.0, -40.0));
+    }
 }
@@ -0,0 +1,254 @@
+use alloc::vec::Vec;
+
+use crate::easing::Easing;
+use crate::float::Float;
+use crate::rng::mix;
+
+/// Where a stagger starts; elements further from it start later.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StaggerFrom {
+    #[default]
+    Start,
+    End,
+    Center,
+    /// Both ends first, meeting in the middle.
+    Edges,
+    /// Outward from the element at this index.
+    Index(usize),
+}
+
+/// Distance measure between grid cells.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum GridAxis {
+    /// Straight-line (euclidean) distance.
+    #[default]
+    Both,
+    /// Column distance only, so whole columns start together.
+    X,
+    /// Row distance only, so whole rows start together.
+    Y,
+}
+
+/// How far apart start times are spread.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StaggerSpread {
+    /// Ticks per unit of distance, e.g. per element in a row.
+    Each(u32),
+    /// Ticks between the first and last start, however many elements there are.
+    Amount(u32),
+}
+
+/// Start-time distribution for staggered elements, in the style of GSAP's `stagger` object.
+///
+/// Each element's distance from the origin is normalized to [0, 1], shaped by the easing and
+/// scaled by the spread. Elements can be laid out in a row-major grid, and a seed replaces the
+/// distances with a deterministic shuffled order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaggerPattern<F: Float> {
+    spread: StaggerSpread,
+    from: StaggerFrom,
+    grid: Option<(usize, usize)>,
+    axis: GridAxis,
+    easing: Easing<F>,
+    seed: Option<u64>,
+}
+
+impl<F: Float> StaggerPattern<F> {
+    /// `ticks` between neighbouring elements.
+    pub fn each(ticks: u32) -> Self {
+        Self::new(StaggerSpread::Each(ticks))
+    }
+
+    /// Spread the starts over `ticks` in total.
+    pub fn amount(ticks: u32) -> Self {
+        Self::new(StaggerSpread::Amount(ticks))
+    }
+
+    pub fn new(spread: StaggerSpread) -> Self {
+        Self {
+            spread,
+            from: StaggerFrom::Start,
+            grid: None,
+            axis: GridAxis::Both,
+            easing: Easing::Linear,
+            seed: None,
+        }
+    }
+
+    pub fn with_from(mut self, from: StaggerFrom) -> Self {
+        self.from = from;
+        self
+    }
+
+    /// Lay elements out row by row in a grid of `columns` x `rows` cells.
+    pub fn with_grid(mut self, columns: usize, rows: usize) -> Self {
+        self.grid = Some((columns.max(1), rows.max(1)));
+        self
+    }
+
+    pub fn with_axis(mut self, axis: GridAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Shape the distribution of start times, e.g. `EaseInQuad` bunches early starts together.
+    pub fn with_easing(mut self, easing: Easing<F>) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Start elements in a shuffled order derived from `seed`, ignoring origin and grid.
+    pub fn with_random_order(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Start tick of each of `count` elements.
+    pub fn offsets(&self, count: usize) -> Vec<u32> {
+        let distances = self.distances(count);
+        let max = distances.iter().copied().fold(F::zero(), F::max);
+        let total = match self.spread {
+            StaggerSpread::Each(ticks) => F::from_f32(ticks as f32) * max,
+            StaggerSpread::Amount(ticks) => F::from_f32(ticks as f32),
+        };
+        distances
+            .into_iter()
+            .map(|distance| {
+                if max == F::zero() {
+                    return 0;
+                }
+                let t = self.easing.evaluate(distance / max);
+                (t * total + F::half()).max(F::zero()).floor().to_f64() as u32
+            })
+            .collect()
+    }
+
+    fn distances(&self, count: usize) -> Vec<F> {
+        if let Some(seed) = self.seed {
+            let mut order: Vec<usize> = (0..count).collect();
+            order.sort_by_key(|&index| mix(seed, index as u64));
+            let mut ranks = alloc::vec![F::zero(); count];
+            for (rank, index) in order.into_iter().enumerate() {
+                ranks[index] = F::from_f32(rank as f32);
+            }
+            return ranks;
+        }
+
+        let (columns, rows) = self.grid.unwrap_or((count.max(1), 1));
+        let cell = |index: usize| {
+            let (x, y) = (index % columns, index / columns);
+            (F::from_f32(x as f32), F::from_f32(y as f32))
+        };
+        let (last_x, last_y) = (
+            F::from_f32(columns.saturating_sub(1) as f32),
+            F::from_f32(rows.saturating_sub(1) as f32),
+        );
+        let origin = match self.from {
+            StaggerFrom::Start | StaggerFrom::Edges => (F::zero(), F::zero()),
+            StaggerFrom::End => (last_x, last_y),
+            StaggerFrom::Center => (last_x * F::half(), last_y * F::half()),
+            StaggerFrom::Index(index) => cell(index),
+        };
+        (0..count)
+            .map(|index| {
+                let (x, y) = cell(index);
+                let (dx, dy) = if self.from == StaggerFrom::Edges {
+                    (x.min(last_x - x), y.min(last_y - y))
+                } else {
+                    ((x - origin.0).abs(), (y - origin.1).abs())
+                };
+                match (self.axis, self.from) {
+                    (GridAxis::X, _) => dx,
+                    (GridAxis::Y, _) => dy,
+                    // The nearest edge, horizontally or vertically.
+                    (GridAxis::Both, StaggerFrom::Edges) if self.grid.is_some() => dx.min(dy),
+                    (GridAxis::Both, StaggerFrom::Edges) => dx,
+                    (GridAxis::Both, _) => (dx * dx + dy * dy).sqrt(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{GridAxis, StaggerFrom, StaggerPattern, StaggerSpread};
+    use crate::easing::Easing;
+
+    #[test]
+    fn stagger_from_origins() {
+        let each = StaggerPattern::<f32>::each(10);
+        assert_eq!(each.offsets(4), [0, 10, 20, 30]);
+        assert_eq!(
+            each.clone().with_from(StaggerFrom::End).offsets(4),
+            [30, 20, 10, 0]
+        );
+        assert_eq!(
+            each.clone().with_from(StaggerFrom::Center).offsets(5),
+            [20, 10, 0, 10, 20]
+        );
+        assert_eq!(
+            each.clone().with_from(StaggerFrom::Edges).offsets(5),
+            [0, 10, 20, 10, 0]
+        );
+        assert_eq!(
+            each.clone().with_from(StaggerFrom::Index(1)).offsets(4),
+            [10, 0, 10, 20]
+        );
+    }
+
+    #[test]
+    fn stagger_amount_and_easing() {
+        let amount = StaggerPattern::<f32>::amount(30);
+        assert_eq!(amount.offsets(4), [0, 10, 20, 30]);
+        assert_eq!(amount.offsets(7).last(), Some(&30));
+        assert_eq!(
+            amount.clone().with_from(StaggerFrom::Center).offsets(4),
+            [30, 10, 10, 30]
+        );
+
+        let eased = amount.clone().with_easing(Easing::EaseInQuad).offsets(4);
+        assert_eq!(eased, [0, 3, 13, 30]);
+        assert_eq!(amount.offsets(1), [0]);
+        assert!(amount.offsets(0).is_empty());
+    }
+
+    #[test]
+    fn stagger_grid_distances() {
+        // 3 x 3 grid from the center: corners are sqrt(2) away, edges 1.
+        let grid = StaggerPattern::<f32>::amount(100)
+            .with_grid(3, 3)
+            .with_from(StaggerFrom::Center);
+        assert_eq!(grid.offsets(9), [100, 71, 100, 71, 0, 71, 100, 71, 100]);
+
+        let rows = StaggerPattern::<f32>::each(5)
+            .with_grid(3, 2)
+            .with_axis(GridAxis::Y);
+        assert_eq!(rows.offsets(6), [0, 0, 0, 5, 5, 5]);
+
+        let columns = StaggerPattern::<f32>::each(5)
+            .with_grid(3, 2)
+            .with_axis(GridAxis::X)
+            .with_from(StaggerFrom::End);
+        assert_eq!(columns.offsets(6), [10, 5, 0, 10, 5, 0]);
+
+        let edges = StaggerPattern::<f32>::each(4)
+            .with_grid(3, 3)
+            .with_from(StaggerFrom::Edges);
+        assert_eq!(edges.offsets(9), [0, 0, 0, 0, 4, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn stagger_random_order_is_seeded() {
+        let random = StaggerPattern::<f32>::new(StaggerSpread::Each(2)).with_random_order(7);
+        let offsets = random.offsets(8);
+        assert_eq!(offsets, random.offsets(8));
+        let mut sorted: Vec<u32> = offsets.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 2, 4, 6, 8, 10, 12, 14]);
+        assert_ne!(offsets, StaggerPattern::<f32>::each(2).offsets(8));
+        assert_ne!(offsets, random.clone().with_random_order(8).offsets(8));
+    }
+}
@@ -0,0 +1,632 @@
+use core::fmt;
+use core::iter::Peekable;
+use core::ops::Deref;
+use core::str::CharIndices;
+
+use crate::float::Float;
+use crate::lerp::{Lerp, Rounding};
+use crate::rng::mix;
+
+/// Stack-allocated UTF-8 string with a fixed byte capacity.
+///
+/// Pushes that would exceed the capacity are rejected whole, so the contents are always valid.
+#[derive(Copy, Clone)]
+pub struct FixedString<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedString<N> {
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    /// Append a character. Returns `false` if it does not fit.
+    pub fn push(&mut self, c: char) -> bool {
+        let mut buf = [0u8; 4];
+        self.push_str(c.encode_utf8(&mut buf))
+    }
+
+    /// Append a string slice. Returns `false` (and appends nothing) if it does not fit.
+    pub fn push_str(&mut self, s: &str) -> bool {
+        let end = self.len + s.len();
+        if end > N {
+            return false;
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for FixedString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq for FixedString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FixedString<N> {}
+
+impl<const N: usize> PartialEq<str> for FixedString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How a [`Counter`] renders its value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Digits after the decimal point (capped at 18).
+    pub decimals: u8,
+    /// Inserted between groups of three integer digits.
+    pub thousands_separator: Option<char>,
+    pub decimal_point: char,
+}
+
+impl NumberFormat {
+    /// Whole numbers with `,` thousands separators.
+    pub fn integer() -> Self {
+        Self {
+            decimals: 0,
+            thousands_separator: Some(','),
+            decimal_point: '.',
+        }
+    }
+
+    /// Fixed precision with `,` thousands separators.
+    pub fn fixed(decimals: u8) -> Self {
+        Self {
+            decimals,
+            ..Self::integer()
+        }
+    }
+
+    pub fn with_separator(mut self, separator: Option<char>) -> Self {
+        self.thousands_separator = separator;
+        self
+    }
+
+    pub fn with_decimal_point(mut self, point: char) -> Self {
+        self.decimal_point = point;
+        self
+    }
+
+    /// Render `value` into `out`, replacing its contents. Output past capacity is dropped.
+    pub fn write<const N: usize>(&self, value: f64, out: &mut FixedString<N>) {
+        out.clear();
+        let decimals = u32::from(self.decimals.min(18));
+        let scale = 10u128.pow(decimals);
+        let scaled = Rounding::Nearest.apply(value.abs() * scale as f64) as u128;
+        if value < 0.0 && scaled != 0 {
+            out.push('-');
+        }
+
+        let mut digits = [0u8; 39];
+        let mut count = 0;
+        let mut whole = scaled / scale;
+        loop {
+            digits[count] = b'0' + (whole % 10) as u8;
+            count += 1;
+            whole /= 10;
+            if whole == 0 {
+                break;
+            }
+        }
+        for i in (0..count).rev() {
+            out.push(digits[i] as char);
+            if i > 0 && i % 3 == 0 {
+                if let Some(separator) = self.thousands_separator {
+                    out.push(separator);
+                }
+            }
+        }
+
+        if decimals > 0 {
+            out.push(self.decimal_point);
+            let fraction = scaled % scale;
+            let mut divisor = scale / 10;
+            while divisor > 0 {
+                out.push((b'0' + ((fraction / divisor) % 10) as u8) as char);
+                divisor /= 10;
+            }
+        }
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::integer()
+    }
+}
+
+/// Animated number display, e.g. a score ticking up to its new total.
+///
+/// Interpolates the numeric value and re-renders it with `from`'s [`NumberFormat`]
+/// into a string of up to `N` bytes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Counter<const N: usize> {
+    value: f64,
+    format: NumberFormat,
+    text: FixedString<N>,
+}
+
+impl<const N: usize> Counter<N> {
+    pub fn new(value: f64, format: NumberFormat) -> Self {
+        let mut text = FixedString::new();
+        format.write(value, &mut text);
+        Self {
+            value,
+            format,
+            text,
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+}
+
+impl<F: Float, const N: usize> Lerp<F> for Counter<N> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        Self::new(
+            self.value + (other.value - self.value) * t.to_f64(),
+            self.format,
+        )
+    }
+}
+
+impl<const N: usize> fmt::Display for Counter<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Unit by which a [`Typewriter`] reveals text.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum RevealUnit {
+    /// One Unicode scalar value at a time.
+    #[default]
+    Char,
+    /// One user-perceived character at a time: combining marks, variation selectors,
+    /// emoji modifiers, ZWJ sequences, and flag pairs stay with their base character.
+    Grapheme,
+    /// One whitespace-separated word at a time.
+    Word,
+}
+
+/// Text revealed progressively, typewriter style.
+///
+/// Tween from [`Typewriter::hidden`] to [`Typewriter::full`]; the shown prefix always ends
+/// on a unit boundary and the final unit appears exactly at the end of the tween.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Typewriter<'a> {
+    text: &'a str,
+    unit: RevealUnit,
+    shown: usize,
+    end: usize,
+}
+
+impl<'a> Typewriter<'a> {
+    /// Show the first `shown` units of `text`.
+    pub fn new(text: &'a str, unit: RevealUnit, shown: usize) -> Self {
+        let end = if shown == 0 {
+            0
+        } else {
+            UnitEnds::new(text, unit)
+                .nth(shown - 1)
+                .unwrap_or(text.len())
+        };
+        let shown = shown.min(unit_count(text, unit));
+        Self {
+            text,
+            unit,
+            shown,
+            end,
+        }
+    }
+
+    pub fn hidden(text: &'a str, unit: RevealUnit) -> Self {
+        Self::new(text, unit, 0)
+    }
+
+    pub fn full(text: &'a str, unit: RevealUnit) -> Self {
+        Self::new(text, unit, usize::MAX)
+    }
+
+    /// Number of units currently shown.
+    pub fn shown(&self) -> usize {
+        self.shown
+    }
+
+    /// Number of units in the whole text.
+    pub fn total(&self) -> usize {
+        unit_count(self.text, self.unit)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.end == self.text.len()
+    }
+
+    /// The revealed prefix.
+    pub fn as_str(&self) -> &'a str {
+        &self.text[..self.end]
+    }
+}
+
+impl<F: Float> Lerp<F> for Typewriter<'_> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        let a = self.shown as f64;
+        let b = other.shown as f64;
+        let shown = Rounding::Floor.apply(a + (b - a) * t.to_f64()).max(0.0);
+        Self::new(self.text, self.unit, shown as usize)
+    }
+}
+
+impl fmt::Display for Typewriter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Characters used by [`Scramble`] unless overridden.
+pub const DEFAULT_SCRAMBLE_CHARSET: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&*+-=?@";
+
+/// How many times per revealed character the scrambled glyphs change.
+const SCRAMBLE_FRAMES_PER_CHAR: f64 = 4.0;
+
+/// "Decoding" text effect: characters resolve left to right while the unresolved tail
+/// cycles through random glyphs.
+///
+/// Deterministic for a given seed and progress. Whitespace is never scrambled. The rendered
+/// text holds up to `N` bytes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Scramble<'a, const N: usize> {
+    target: &'a str,
+    charset: &'a str,
+    seed: u64,
+    progress: f64,
+    text: FixedString<N>,
+}
+
+impl<'a, const N: usize> Scramble<'a, N> {
+    /// Fully scrambled `target`.
+    pub fn new(target: &'a str, seed: u64) -> Self {
+        Self::at(target, DEFAULT_SCRAMBLE_CHARSET, seed, 0.0)
+    }
+
+    /// Fully resolved `target`.
+    pub fn revealed(target: &'a str, seed: u64) -> Self {
+        Self::at(target, DEFAULT_SCRAMBLE_CHARSET, seed, 1.0)
+    }
+
+    /// Draw scrambled glyphs from `charset` instead of the default.
+    pub fn with_charset(self, charset: &'a str) -> Self {
+        Self::at(self.target, charset, self.seed, self.progress)
+    }
+
+    /// Fraction of characters resolved, in [0, 1].
+    pub fn progress(&self) -> f64 {
+        self.progress
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+
+    fn at(target: &'a str, charset: &'a str, seed: u64, progress: f64) -> Self {
+        let progress = progress.clamp(0.0, 1.0);
+        let total = target.chars().count();
+        let resolved = Rounding::Floor.apply(progress * total as f64) as usize;
+        let frame = Rounding::Floor.apply(progress * total as f64 * SCRAMBLE_FRAMES_PER_CHAR);
+        let frame_seed = mix(seed, frame as u64);
+        let glyphs = charset.chars().count();
+
+        let mut text = FixedString::new();
+        for (i, c) in target.chars().enumerate() {
+            let shown = if i < resolved || c.is_whitespace() || glyphs == 0 {
+                c
+            } else {
+                let pick = mix(frame_seed, i as u64) % glyphs as u64;
+                charset.chars().nth(pick as usize).unwrap_or(c)
+            };
+            if !text.push(shown) {
+                break;
+            }
+        }
+
+        Self {
+            target,
+            charset,
+            seed,
+            progress,
+            text,
+        }
+    }
+}
+
+impl<F: Float, const N: usize> Lerp<F> for Scramble<'_, N> {
+    fn lerp(&self, other: &Self, t: F) -> Self {
+        let progress = self.progress + (other.progress - self.progress) * t.to_f64();
+        Self::at(self.target, self.charset, self.seed, progress)
+    }
+}
+
+impl<const N: usize> fmt::Display for Scramble<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn unit_count(text: &str, unit: RevealUnit) -> usize {
+    UnitEnds::new(text, unit).count()
+}
+
+/// Yields the byte offset just past each reveal unit.
+struct UnitEnds<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    unit: RevealUnit,
+}
+
+impl<'a> UnitEnds<'a> {
+    fn new(text: &'a str, unit: RevealUnit) -> Self {
+        Self {
+            chars: text.char_indices().peekable(),
+            unit,
+        }
+    }
+
+    fn next_grapheme(&mut self, start: usize, first: char) -> usize {
+        let mut end = start + first.len_utf8();
+        if first == '\r' {
+            if let Some(&(i, '\n')) = self.chars.peek() {
+                self.chars.next();
+                return i + 1;
+            }
+            return end;
+        }
+
+        let mut prev = first;
+        let mut flag_paired = false;
+        while let Some(&(i, next)) = self.chars.peek() {
+            if next == '\u{200D}' {
+                self.chars.next();
+                end = i + next.len_utf8();
+                if let Some((j, joined)) = self.chars.next() {
+                    end = j + joined.len_utf8();
+                    prev = joined;
+                }
+            } else if extends_grapheme(next) {
+                self.chars.next();
+                end = i + next.len_utf8();
+            } else if is_regional_indicator(prev) && is_regional_indicator(next) && !flag_paired {
+                self.chars.next();
+                end = i + next.len_utf8();
+                flag_paired = true;
+            } else {
+                break;
+            }
+        }
+        end
+    }
+
+    fn next_word(&mut self) -> Option<usize> {
+        while let Some(&(_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+        let mut end = None;
+        while let Some(&(i, c)) = self.chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+            end = Some(i + c.len_utf8());
+        }
+        end
+    }
+}
+
+impl Iterator for UnitEnds<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self.unit {
+            RevealUnit::Char => self.chars.next().map(|(i, c)| i + c.len_utf8()),
+            RevealUnit::Grapheme => {
+                let (start, first) = self.chars.next()?;
+                Some(self.next_grapheme(start, first))
+            }
+            RevealUnit::Word => self.next_word(),
+        }
+    }
+}
+
+fn extends_grapheme(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036F
+            | 0x0483..=0x0489
+            | 0x0591..=0x05BD
+            | 0x0610..=0x061A
+            | 0x064B..=0x065F
+            | 0x1AB0..=0x1AFF
+            | 0x1DC0..=0x1DFF
+            | 0x20D0..=0x20FF
+            | 0xFE00..=0xFE0F
+            | 0xFE20..=0xFE2F
+            | 0x1F3FB..=0x1F3FF
+            | 0xE0020..=0xE007F
+            | 0xE0100..=0xE01EF
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1F1E6..=0x1F1FF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Counter, FixedString, NumberFormat, RevealUnit, Scramble, Typewriter};
+    use crate::lerp::Lerp;
+    use crate::tween::Tween;
+
+    #[test]
+    fn fixed_string_rejects_overflow() {
+        let mut s = FixedString::<4>::new();
+        assert!(s.push_str("abc"));
+        assert!(!s.push('é'));
+        assert!(s.push('d'));
+        assert_eq!(s, "abcd");
+    }
+
+    #[test]
+    fn counter_formats_separators_and_decimals() {
+        let c = Counter::<32>::new(1_234_567.0, NumberFormat::integer());
+        assert_eq!(c.as_str(), "1,234,567");
+        let c = Counter::<32>::new(-9_876.545, NumberFormat::fixed(2));
+        assert_eq!(c.as_str(), "-9,876.55");
+        let c = Counter::<32>::new(0.5, NumberFormat::fixed(3).with_separator(None));
+        assert_eq!(c.as_str(), "0.500");
+        let c = Counter::<32>::new(
+            1234.5,
+            NumberFormat::fixed(1)
+                .with_separator(Some('.'))
+                .with_decimal_point(','),
+        );
+        assert_eq!(c.as_str(), "1.234,5");
+    }
+
+    #[test]
+    fn counter_tweens_score() {
+        let from = Counter::<16>::new(0.0, NumberFormat::integer());
+        let to = Counter::<16>::new(10_000.0, NumberFormat::integer());
+        let mut tween = Tween::<_, f32>::new(from, to, 4);
+        tween.tick();
+        assert_eq!(tween.tick().as_str(), "5,000");
+        tween.tick();
+        assert_eq!(tween.tick().as_str(), "10,000");
+    }
+
+    #[test]
+    fn typewriter_by_char_and_word() {
+        let text = "hello brave world";
+        let hidden = Typewriter::hidden(text, RevealUnit::Char);
+        let full = Typewriter::full(text, RevealUnit::Char);
+        assert_eq!(hidden.lerp(&full, 5.0f32 / 17.0).as_str(), "hello");
+        assert_eq!(hidden.lerp(&full, 1.0f32).as_str(), text);
+
+        let hidden = Typewriter::hidden(text, RevealUnit::Word);
+        let full = Typewriter::full(text, RevealUnit::Word);
+        assert_eq!(full.total(), 3);
+        assert_eq!(hidden.lerp(&full, 0.7f32).as_str(), "hello brave");
+    }
+
+    #[test]
+    fn typewriter_keeps_graphemes_whole() {
+        let text = "e\u{301}👍🏽🇯🇵!";
+        let full = Typewriter::full(text, RevealUnit::Grapheme);
+        assert_eq!(full.total(), 4);
+        assert_eq!(
+            Typewriter::new(text, RevealUnit::Grapheme, 1).as_str(),
+            "e\u{301}"
+        );
+        assert_eq!(
+            Typewriter::new(text, RevealUnit::Grapheme, 3).as_str(),
+            "e\u{301}👍🏽🇯🇵"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn typewriter_in_keyframes() {
+        use crate::keyframes::{Keyframe, Keyframes};
+        use alloc::vec;
+
+        let text = "abcd";
+        let mut keyframes = Keyframes::<_, f32>::new(vec![
+            Keyframe::new(Typewriter::hidden(text, RevealUnit::Char), 0),
+            Keyframe::new(Typewriter::full(text, RevealUnit::Char), 4),
+        ]);
+        keyframes.tick();
+        assert_eq!(keyframes.tick().as_str(), "ab");
+    }
+
+    #[test]
+    fn scramble_resolves_deterministically() {
+        let from = Scramble::<32>::new("ACCESS GRANTED", 7);
+        let to = Scramble::<32>::revealed("ACCESS GRANTED", 7);
+        let a = from.lerp(&to, 0.5f32);
+        let b = from.lerp(&to, 0.5f32);
+        assert_eq!(a.as_str(), b.as_str());
+        assert!(a.as_str().starts_with("ACCESS "));
+        assert_eq!(a.as_str().chars().count(), 14);
+        assert_eq!(from.lerp(&to, 1.0f32).as_str(), "ACCESS GRANTED");
+        assert_eq!(from.as_str().chars().nth(6), Some(' '));
+    }
+}
@@ -1,7 +1,15 @@
+use alloc::boxed::Box;
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
 
+use crate::animation::{Animation, DynAnimation};
+use crate::error::TweenError;
 use crate::float::Float;
-use crate::loop_mode::LoopMode;
+use crate::loop_mode::{LoopMode, PlayDirection};
+use crate::marker::{Marker, MarkerTrack, SeekEvents};
+use crate::slots::Slots;
 use crate::state::TweenState;
 use crate::tween::TweenId;
 
@@ -11,82 +19,517 @@ pub struct TimelineEntry {
     pub id: TweenId,
     pub start_tick: u32,
     pub duration: u32,
+    /// Track the entry blends on; see [`BlendMode`].
+    pub track: u32,
+}
+
+/// How overlapping entries on one track share weight in [`Timeline::blended`].
+///
+/// Entries on a track stack in order of start tick, later-added entries on top for equal starts.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// The top active entry has full weight and the rest none.
+    #[default]
+    Override,
+    /// Each entry fades in over its overlap with the entries below it, which fade out
+    /// together, so weights always sum to 1.
+    Crossfade,
+    /// Every active entry has full weight.
+    Additive,
+}
+
+/// An active entry with its blend weight, as reported by [`Timeline::tick_blended`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BlendedEntry<F> {
+    pub id: TweenId,
+    pub track: u32,
+    pub progress: F,
+    pub weight: F,
+}
+
+impl TimelineEntry {
+    /// Tick the entry ends at.
+    pub fn end_tick(&self) -> u32 {
+        self.start_tick.saturating_add(self.duration)
+    }
 }
 
 /// Heterogeneous animation timeline.
 #[derive(Clone, Debug)]
 pub struct Timeline {
+    /// In the order added.
     entries: Vec<TimelineEntry>,
-    next_id: u32,
+    /// Ids to indices into `entries`.
+    slots: Slots,
+    /// Tracks not using the default blend mode.
+    blend_modes: Vec<(u32, BlendMode)>,
+    labels: Vec<(String, u32)>,
+    nested: Vec<(TweenId, NestedTimeline)>,
+    markers: MarkerTrack,
     elapsed: u32,
     state: TweenState,
     loop_mode: LoopMode,
     loops_completed: u32,
+    direction: PlayDirection,
 }
 
 impl Timeline {
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
-            next_id: 0,
+            slots: Slots::new(),
+            blend_modes: Vec::new(),
+            labels: Vec::new(),
+            nested: Vec::new(),
+            markers: MarkerTrack::new(),
             elapsed: 0,
             state: TweenState::Playing,
             loop_mode: LoopMode::Once,
             loops_completed: 0,
+            direction: PlayDirection::Forward,
         }
     }
 
     /// Add an entry. Returns the TweenId for lookup.
     pub fn add(&mut self, start_tick: u32, duration: u32) -> TweenId {
-        let id = TweenId(self.next_id);
-        self.next_id = self.next_id.saturating_add(1);
+        self.add_to_track(0, start_tick, duration)
+    }
+
+    /// Add an entry on a track other than the default track 0.
+    pub fn add_to_track(&mut self, track: u32, start_tick: u32, duration: u32) -> TweenId {
+        let id = self.slots.insert(self.entries.len());
         self.entries.push(TimelineEntry {
             id,
             start_tick,
             duration,
+            track,
         });
         id
     }
 
+    /// Remove an entry. Its id, and any copies of it, stop working.
+    pub fn remove(&mut self, id: TweenId) -> Result<TimelineEntry, TweenError> {
+        let position = self.slots.remove(id).ok_or(TweenError::InvalidId)?;
+        let entry = self.entries.remove(position);
+        self.slots.close_gap(position);
+        self.nested.retain(|(nested_id, _)| *nested_id != id);
+        Ok(entry)
+    }
+
+    /// Move an entry to start at `tick`.
+    pub fn set_start(&mut self, id: TweenId, tick: u32) -> Result<(), TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.entries[position].start_tick = tick;
+        Ok(())
+    }
+
+    /// Change how many ticks an entry lasts.
+    pub fn set_duration(&mut self, id: TweenId, ticks: u32) -> Result<(), TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.entries[position].duration = ticks;
+        Ok(())
+    }
+
+    /// Move an entry to another track.
+    pub fn set_track(&mut self, id: TweenId, track: u32) -> Result<(), TweenError> {
+        let position = self.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.entries[position].track = track;
+        Ok(())
+    }
+
+    pub fn set_blend_mode(&mut self, track: u32, mode: BlendMode) {
+        self.blend_modes.retain(|&(other, _)| other != track);
+        if mode != BlendMode::default() {
+            self.blend_modes.push((track, mode));
+        }
+    }
+
+    pub fn with_blend_mode(mut self, track: u32, mode: BlendMode) -> Self {
+        self.set_blend_mode(track, mode);
+        self
+    }
+
+    pub fn blend_mode(&self, track: u32) -> BlendMode {
+        self.blend_modes
+            .iter()
+            .find(|&&(other, _)| other == track)
+            .map(|&(_, mode)| mode)
+            .unwrap_or_default()
+    }
+
+    /// The entry behind `id`, unless it has been removed.
+    pub fn entry(&self, id: TweenId) -> Option<&TimelineEntry> {
+        self.position_of(id).map(|position| &self.entries[position])
+    }
+
+    pub fn contains(&self, id: TweenId) -> bool {
+        self.position_of(id).is_some()
+    }
+
+    /// Entries in the order added.
+    pub fn entries(&self) -> &[TimelineEntry] {
+        &self.entries
+    }
+
+    /// Entries active at `tick`, as [`tick`](Self::tick) would report them there.
+    pub fn entries_at(&self, tick: u32) -> impl Iterator<Item = &TimelineEntry> + '_ {
+        self.active_at::<f32>(tick)
+            .filter_map(move |(id, _)| self.entry(id))
+    }
+
+    /// Entries overlapping a range of ticks, each counted as covering its start through its
+    /// end tick.
+    pub fn entries_in_range(
+        &self,
+        range: impl RangeBounds<u32>,
+    ) -> impl Iterator<Item = &TimelineEntry> + '_ {
+        let from = match range.start_bound() {
+            Bound::Included(&tick) => tick,
+            Bound::Excluded(&tick) => tick.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let to = match range.end_bound() {
+            Bound::Included(&tick) => Some(tick),
+            Bound::Excluded(&tick) => tick.checked_sub(1),
+            Bound::Unbounded => Some(u32::MAX),
+        };
+        self.entries.iter().filter(move |entry| {
+            to.is_some_and(|to| from <= to && entry.start_tick <= to && entry.end_tick() >= from)
+        })
+    }
+
+    /// Add a nested timeline starting at `start_tick`. Its entry lasts as long as the nested
+    /// timeline takes to play its clip range at its time scale, through all of its loops.
+    pub fn add_timeline(&mut self, start_tick: u32, nested: NestedTimeline) -> TweenId {
+        let id = self.add(start_tick, nested.span());
+        self.nested.push((id, nested));
+        id
+    }
+
+    /// The nested timeline added under `id`.
+    pub fn nested(&self, id: TweenId) -> Option<&NestedTimeline> {
+        self.nested
+            .iter()
+            .find(|(nested_id, _)| *nested_id == id)
+            .map(|(_, nested)| nested)
+    }
+
+    /// Add an event marker, reported by [`fired_events`](Self::fired_events) whenever playback
+    /// crosses `tick`, on every loop iteration and in either direction.
+    pub fn add_marker(&mut self, tick: u32, event: u32) {
+        self.markers.add(Marker::new(tick, event));
+    }
+
+    /// Markers, ordered by tick.
+    pub fn markers(&self) -> &[Marker] {
+        self.markers.markers()
+    }
+
+    /// Markers crossed by the latest `tick`, `tick_n` or `seek`, in the order playback passed
+    /// them.
+    pub fn fired_events(&self) -> &[Marker] {
+        self.markers.fired()
+    }
+
+    /// Whether seeking fires the markers it jumps over. Seeks are silent by default.
+    pub fn with_seek_events(mut self, seek_events: SeekEvents) -> Self {
+        self.markers.set_seek_events(seek_events);
+        self
+    }
+
+    /// Name a tick, e.g. to [`seek_label`](Self::seek_label) to it later. Re-adding a name
+    /// moves it.
+    pub fn add_label(&mut self, name: impl Into<String>, tick: u32) {
+        let name = name.into();
+        match self.labels.iter_mut().find(|(label, _)| *label == name) {
+            Some(label) => label.1 = tick,
+            None => self.labels.push((name, tick)),
+        }
+    }
+
+    /// Tick the label names.
+    pub fn label_tick(&self, name: &str) -> Option<u32> {
+        self.labels
+            .iter()
+            .find(|(label, _)| label == name)
+            .map(|&(_, tick)| tick)
+    }
+
+    /// Seek to a label, keeping the current direction.
+    pub fn seek_label(&mut self, name: &str) -> Result<(), TweenError> {
+        let tick = self.label_tick(name).ok_or(TweenError::UnknownLabel)?;
+        self.seek(tick);
+        Ok(())
+    }
+
     /// Advance by one tick. Returns (TweenId, progress) for active entries.
     pub fn tick<F: Float>(&mut self) -> Vec<(TweenId, F)> {
+        let mut active = Vec::new();
+        self.tick_into(&mut active);
+        active
+    }
+
+    /// Advance by `n` ticks as one step, so [`fired_events`](Self::fired_events) reports every
+    /// marker crossed on the way. Returns the entries active where it stops.
+    pub fn tick_n<F: Float>(&mut self, n: u32) -> Vec<(TweenId, F)> {
+        self.markers.hold();
+        let mut position = Some(self.position());
+        for _ in 0..n {
+            position = self.advance();
+        }
+        self.markers.release();
+        position.map_or_else(Vec::new, |position| self.active_at(position).collect())
+    }
+
+    /// [`tick`](Self::tick) that appends the active entries to `out`, e.g. a reused buffer,
+    /// instead of allocating.
+    pub fn tick_into<F: Float>(&mut self, out: &mut impl Extend<(TweenId, F)>) {
+        if let Some(position) = self.advance() {
+            out.extend(self.active_at(position));
+        }
+    }
+
+    /// Total duration (end tick of last entry).
+    pub fn total_duration(&self) -> u32 {
+        self.entries
+            .iter()
+            .map(|e| e.start_tick.saturating_add(e.duration))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Seek to a specific tick, keeping the current direction.
+    pub fn seek(&mut self, tick: u32) {
+        let total = self.total_duration();
+        let from = self.position();
+        self.elapsed = match self.direction {
+            PlayDirection::Forward => tick,
+            PlayDirection::Backward => total.saturating_sub(tick),
+        };
+        self.markers.seek(from, self.position());
+        if self.elapsed < total {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Current playback direction. Backward legs run from the end, so entry progress falls.
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current tick.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight, continuing from the current tick. A finished timeline plays
+    /// back the way it came.
+    pub fn reverse(&mut self) {
+        self.direction = self.direction.reversed();
+        let total = self.total_duration();
+        self.elapsed = total - self.elapsed.min(total);
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a leg toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+    }
+
+    /// Entries active at the current tick, as returned by [`tick`](Self::tick).
+    pub fn active<F: Float>(&self) -> Vec<(TweenId, F)> {
+        self.active_iter().collect()
+    }
+
+    /// [`tick`](Self::tick) that also reports each active entry's track and blend weight.
+    pub fn tick_blended<F: Float>(&mut self) -> Vec<BlendedEntry<F>> {
+        let mut active = Vec::new();
+        self.tick_blended_into(&mut active);
+        active
+    }
+
+    /// [`tick_blended`](Self::tick_blended) that appends to `out`. Blending still sorts the
+    /// active entries in a scratch buffer.
+    pub fn tick_blended_into<F: Float>(&mut self, out: &mut impl Extend<BlendedEntry<F>>) {
+        if let Some(position) = self.advance() {
+            out.extend(self.blended_at(position));
+        }
+    }
+
+    /// Entries active at the current tick with their blend weights.
+    pub fn blended<F: Float>(&self) -> Vec<BlendedEntry<F>> {
+        self.blended_at(self.position())
+    }
+
+    /// Entries active at the current tick, without collecting them.
+    pub fn active_iter<'a, F: Float + 'a>(&'a self) -> impl Iterator<Item = (TweenId, F)> + 'a {
+        self.active_at(self.position())
+    }
+
+    /// Normalized position [0, 1] of the playhead.
+    pub fn progress<F: Float>(&self) -> F {
+        let total = self.total_duration();
+        if total == 0 {
+            return F::one();
+        }
+        F::from_f32(self.position().min(total) as f32 / total as f32)
+    }
+
+    /// Leaf entries active at the current tick, descending into nested timelines, with their
+    /// progress at the local time each nested timeline resolves to.
+    pub fn active_leaves<F: Float>(&self) -> Vec<ActiveLeaf<F>> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(self.position(), F::zero(), &mut Vec::new(), &mut leaves);
+        leaves
+    }
+
+    /// Collect leaves active `fraction` of a tick after `tick`; nested timelines can resolve to
+    /// fractional local times.
+    fn collect_leaves<F: Float>(
+        &self,
+        tick: u32,
+        fraction: F,
+        path: &mut Vec<TweenId>,
+        leaves: &mut Vec<ActiveLeaf<F>>,
+    ) {
+        for entry in &self.entries {
+            let Some(progress) = entry_progress(entry, tick, fraction) else {
+                continue;
+            };
+            path.push(entry.id);
+            match self.nested(entry.id) {
+                Some(nested) => {
+                    let offset = F::from_f64((tick - entry.start_tick) as f64) + fraction;
+                    let local = nested.local_tick(offset);
+                    let whole = local.floor();
+                    nested.timeline.collect_leaves(
+                        whole.to_f64() as u32,
+                        local - whole,
+                        path,
+                        leaves,
+                    );
+                }
+                None => leaves.push(ActiveLeaf {
+                    path: path.clone(),
+                    progress,
+                }),
+            }
+            path.pop();
+        }
+    }
+
+    /// Advance by one tick and return the tick reached, before any loop wraps around. `None`
+    /// when there is nothing to play.
+    fn advance(&mut self) -> Option<u32> {
         if self.state != TweenState::Playing {
-            return self.active_entries(self.elapsed);
+            self.markers.clear();
+            return Some(self.position());
         }
 
         let total = self.total_duration();
         if total == 0 {
+            self.markers.step(0, 0);
             self.state = TweenState::Finished;
-            return Vec::new();
+            return None;
         }
 
+        let from = self.position();
         if self.elapsed < total {
             self.elapsed += 1;
         }
 
-        let active = self.active_entries(self.elapsed);
+        let position = self.position();
+        self.markers.step(from, position);
 
         if self.elapsed >= total {
             self.on_iteration_complete();
         }
 
-        active
+        Some(position)
     }
 
-    /// Total duration (end tick of last entry).
-    pub fn total_duration(&self) -> u32 {
-        self.entries
+    /// Active entries at `tick` with their weights, in the order added. Entries are grouped by
+    /// track and stacked bottom to top once, so each track costs one sort and two passes.
+    fn blended_at<F: Float>(&self, tick: u32) -> Vec<BlendedEntry<F>> {
+        let mut layers: Vec<(usize, BlendedEntry<F>)> = self
+            .entries
             .iter()
-            .map(|e| e.start_tick.saturating_add(e.duration))
-            .max()
-            .unwrap_or(0)
+            .enumerate()
+            .filter_map(|(index, entry)| {
+                let progress = entry_progress(entry, tick, F::zero())?;
+                let layer = BlendedEntry {
+                    id: entry.id,
+                    track: entry.track,
+                    progress,
+                    weight: F::one(),
+                };
+                Some((index, layer))
+            })
+            .collect();
+        layers.sort_unstable_by_key(|&(index, _)| {
+            let entry = &self.entries[index];
+            (entry.track, entry.start_tick, index)
+        });
+
+        let mut rest = &mut layers[..];
+        while let Some((first, _)) = rest.first() {
+            let track = self.entries[*first].track;
+            let len = rest
+                .iter()
+                .position(|(index, _)| self.entries[*index].track != track)
+                .unwrap_or(rest.len());
+            let (stack, tail) = rest.split_at_mut(len);
+            self.blend_stack(stack, tick);
+            rest = tail;
+        }
+
+        layers.sort_unstable_by_key(|&(index, _)| index);
+        layers.into_iter().map(|(_, layer)| layer).collect()
     }
 
-    /// Seek to a specific tick.
-    pub fn seek(&mut self, tick: u32) {
-        self.elapsed = tick;
-        if self.elapsed < self.total_duration() {
-            self.state = TweenState::Playing;
+    /// Weigh one track's active entries, ordered bottom to top: each fades in over the entries
+    /// below it and keeps that share of what the entries above leave.
+    fn blend_stack<F: Float>(&self, stack: &mut [(usize, BlendedEntry<F>)], tick: u32) {
+        let mode = self.blend_mode(self.entries[stack[0].0].track);
+        if mode == BlendMode::Additive {
+            return;
+        }
+        let mut below_end = None;
+        for (index, layer) in stack.iter_mut() {
+            let entry = &self.entries[*index];
+            layer.weight = match below_end {
+                Some(end) if mode == BlendMode::Crossfade && end > entry.start_tick => {
+                    let faded = tick.saturating_sub(entry.start_tick) as f64;
+                    let overlap = (end - entry.start_tick) as f64;
+                    F::from_f64(faded / overlap).min(F::one())
+                }
+                _ => F::one(),
+            };
+            below_end = below_end.max(Some(entry.end_tick()));
+        }
+        let mut left = F::one();
+        for (_, layer) in stack.iter_mut().rev() {
+            let fade_in = layer.weight;
+            layer.weight = fade_in * left;
+            left = left * (F::one() - fade_in);
+        }
+    }
+
+    /// Index of the entry behind `id` in `entries`.
+    fn position_of(&self, id: TweenId) -> Option<usize> {
+        self.slots.get(id)
+    }
+
+    /// Tick on the timeline the playhead is at.
+    fn position(&self) -> u32 {
+        match self.direction {
+            PlayDirection::Forward => self.elapsed,
+            PlayDirection::Backward => self.total_duration().saturating_sub(self.elapsed),
         }
     }
 
@@ -94,6 +537,10 @@ impl Timeline {
         self.state == TweenState::Finished
     }
 
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
     pub fn with_loop(mut self, mode: LoopMode) -> Self {
         self.loop_mode = mode;
         self
@@ -102,28 +549,19 @@ impl Timeline {
     pub fn reset(&mut self) {
         self.elapsed = 0;
         self.loops_completed = 0;
+        self.direction = PlayDirection::Forward;
         self.state = TweenState::Playing;
+        self.markers.clear();
+        self.markers.restart();
     }
 
-    fn active_entries<F: Float>(&self, tick: u32) -> Vec<(TweenId, F)> {
-        let mut active = Vec::new();
-        for entry in &self.entries {
-            if entry.duration == 0 {
-                if tick == entry.start_tick {
-                    active.push((entry.id, F::one()));
-                }
-                continue;
-            }
-
-            let end_tick = entry.start_tick.saturating_add(entry.duration);
-            if tick >= entry.start_tick && tick <= end_tick {
-                let local_elapsed = tick.saturating_sub(entry.start_tick);
-                let progress = F::from_f32(local_elapsed as f32 / entry.duration as f32)
-                    .clamp(F::zero(), F::one());
-                active.push((entry.id, progress));
-            }
-        }
-        active
+    fn active_at<'a, F: Float + 'a>(
+        &'a self,
+        tick: u32,
+    ) -> impl Iterator<Item = (TweenId, F)> + 'a {
+        self.entries.iter().filter_map(move |entry| {
+            entry_progress(entry, tick, F::zero()).map(|progress| (entry.id, progress))
+        })
     }
 
     fn on_iteration_complete(&mut self) {
@@ -137,11 +575,18 @@ impl Timeline {
                     self.state = TweenState::Finished;
                 } else {
                     self.elapsed = 0;
+                    self.markers.restart();
                 }
             }
-            LoopMode::Infinite | LoopMode::PingPong => {
+            LoopMode::Infinite => {
+                self.loops_completed += 1;
+                self.elapsed = 0;
+                self.markers.restart();
+            }
+            LoopMode::PingPong => {
                 self.loops_completed += 1;
                 self.elapsed = 0;
+                self.direction = self.direction.reversed();
             }
             LoopMode::PingPongCount(count) => {
                 self.loops_completed += 1;
@@ -150,6 +595,7 @@ impl Timeline {
                     self.state = TweenState::Finished;
                 } else {
                     self.elapsed = 0;
+                    self.direction = self.direction.reversed();
                 }
             }
         }
@@ -162,10 +608,487 @@ impl Default for Timeline {
     }
 }
 
+/// Progress of `entry` `fraction` of a tick after `tick`, or `None` when it is not active.
+///
+/// Ticks are compared as integers, so entries far beyond `f32` precision start and end on the
+/// right tick.
+fn entry_progress<F: Float>(entry: &TimelineEntry, tick: u32, fraction: F) -> Option<F> {
+    if entry.duration == 0 {
+        return (tick == entry.start_tick).then(F::one);
+    }
+
+    let local = tick.checked_sub(entry.start_tick)?;
+    if local > entry.duration || (local == entry.duration && fraction > F::zero()) {
+        return None;
+    }
+    let progress = if fraction == F::zero() {
+        F::from_f64(local as f64 / entry.duration as f64)
+    } else {
+        (F::from_f64(local as f64) + fraction) / F::from_f64(entry.duration as f64)
+    };
+    Some(progress.min(F::one()))
+}
+
+/// A leaf entry reported by [`Timeline::active_leaves`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActiveLeaf<F> {
+    /// Ids from the outer timeline's entry down to the leaf's id in its own timeline.
+    pub path: Vec<TweenId>,
+    pub progress: F,
+}
+
+/// A timeline placed inside another, with its own time scale and clip range.
+///
+/// The nested timeline plays its clip range once per iteration of its own loop mode, so
+/// `Count(3)` plays the range three times and ping-pong modes play alternate passes backward.
+/// `Infinite` loops play a single pass, since a parent entry needs an end. Its own playhead is
+/// not used: the parent's position decides what it shows.
+#[derive(Clone, Debug)]
+pub struct NestedTimeline {
+    timeline: Timeline,
+    time_scale: f32,
+    clip: Option<(u32, u32)>,
+}
+
+impl NestedTimeline {
+    pub fn new(timeline: Timeline) -> Self {
+        Self {
+            timeline,
+            time_scale: 1.0,
+            clip: None,
+        }
+    }
+
+    /// Local ticks per parent tick: 2.0 plays twice as fast, 0.5 at half speed, and negative
+    /// values play backward from the end of the clip range. Zero holds the first tick of the
+    /// range for one pass.
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Play only local ticks `start..end` of the nested timeline.
+    pub fn with_clip(mut self, start: u32, end: u32) -> Self {
+        self.clip = Some((start, end.max(start)));
+        self
+    }
+
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Local ticks played per pass.
+    pub fn clip(&self) -> (u32, u32) {
+        self.clip.unwrap_or((0, self.timeline.total_duration()))
+    }
+
+    /// Parent ticks needed to play every pass.
+    pub fn span(&self) -> u32 {
+        let (start, end) = self.clip();
+        let local = (end - start).saturating_mul(self.passes());
+        let speed = self.time_scale.abs();
+        if speed == 0.0 {
+            return end - start;
+        }
+        let span = -Float::floor(-(local as f32 / speed));
+        span as u32
+    }
+
+    fn passes(&self) -> u32 {
+        match self.timeline.loop_mode {
+            LoopMode::Once | LoopMode::Infinite => 1,
+            LoopMode::Count(count) => count.max(1),
+            LoopMode::PingPong => 2,
+            LoopMode::PingPongCount(count) => count.saturating_mul(2).max(1),
+        }
+    }
+
+    /// Local tick shown `offset` parent ticks after the entry starts.
+    fn local_tick<F: Float>(&self, offset: F) -> F {
+        let (start, end) = self.clip();
+        let length = F::from_f32((end - start) as f32);
+        if length == F::zero() {
+            return F::from_f32(start as f32);
+        }
+        let passes = self.passes();
+        let played =
+            (offset * F::from_f32(self.time_scale.abs())).min(length * F::from_f32(passes as f32));
+        let pass = (played / length).floor().to_f32() as u32;
+        let (pass, within) = if pass >= passes {
+            (passes - 1, length)
+        } else {
+            (pass, played - length * F::from_f32(pass as f32))
+        };
+        let ping_pong = matches!(
+            self.timeline.loop_mode,
+            LoopMode::PingPong | LoopMode::PingPongCount(_)
+        );
+        let backward = (ping_pong && pass % 2 == 1) != (self.time_scale < 0.0);
+        let within = if backward { length - within } else { within };
+        F::from_f32(start as f32) + within
+    }
+}
+
+/// Where to place an entry on an [`AnimationTimeline`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimelinePosition {
+    /// At an absolute tick.
+    Tick(u32),
+    /// At a label added with [`AnimationTimeline::add_label`].
+    Label(String),
+    /// Right after the current last entry ends.
+    End,
+}
+
+impl From<u32> for TimelinePosition {
+    fn from(tick: u32) -> Self {
+        Self::Tick(tick)
+    }
+}
+
+impl From<&str> for TimelinePosition {
+    fn from(label: &str) -> Self {
+        Self::Label(label.into())
+    }
+}
+
+impl From<String> for TimelinePosition {
+    fn from(label: String) -> Self {
+        Self::Label(label)
+    }
+}
+
+/// A [`Timeline`] that owns its entries' animations and drives them.
+///
+/// Each child is any [`Animation`] producing `T`, placed at a start tick and lasting its
+/// `total_duration`. The timeline ticks children with their local time, so seeking, looping
+/// and reverse playback move every child to the matching point. Children of unknown duration,
+/// such as springs, occupy a single tick on the timeline but keep running after their start.
+#[derive(Clone)]
+pub struct AnimationTimeline<T, F: Float> {
+    timeline: Timeline,
+    /// One per timeline entry, in the same order.
+    children: Vec<TimelineChild<T, F>>,
+}
+
+#[derive(Clone)]
+struct TimelineChild<T, F: Float> {
+    animation: Box<dyn DynAnimation<F, T>>,
+    /// Ticks of local time the animation is at.
+    local: u32,
+}
+
+impl<T, F: Float> AnimationTimeline<T, F> {
+    pub fn new() -> Self {
+        Self {
+            timeline: Timeline::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_loop(mut self, mode: LoopMode) -> Self {
+        self.timeline.loop_mode = mode;
+        self
+    }
+
+    /// Add an animation after the current last entry.
+    pub fn add<A>(&mut self, animation: A) -> TweenId
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        let start = self.timeline.total_duration();
+        self.insert(start, animation)
+    }
+
+    /// Add an animation starting at a tick or label. Fails if the label is unknown.
+    pub fn add_at<A>(
+        &mut self,
+        position: impl Into<TimelinePosition>,
+        animation: A,
+    ) -> Result<TweenId, TweenError>
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        let start = self.resolve(&position.into())?;
+        Ok(self.insert(start, animation))
+    }
+
+    /// Name a tick or the end of the timeline so far, for [`add_at`](Self::add_at) and
+    /// [`seek_label`](Self::seek_label).
+    pub fn add_label(
+        &mut self,
+        name: impl Into<String>,
+        position: impl Into<TimelinePosition>,
+    ) -> Result<(), TweenError> {
+        let tick = self.resolve(&position.into())?;
+        self.timeline.add_label(name, tick);
+        Ok(())
+    }
+
+    /// Add an event marker; see [`Timeline::add_marker`].
+    pub fn add_marker(&mut self, tick: u32, event: u32) {
+        self.timeline.add_marker(tick, event);
+    }
+
+    /// Markers crossed by the latest `tick`, `tick_n` or `seek`.
+    pub fn fired_events(&self) -> &[Marker] {
+        self.timeline.fired_events()
+    }
+
+    pub fn with_seek_events(mut self, seek_events: SeekEvents) -> Self {
+        self.timeline = self.timeline.with_seek_events(seek_events);
+        self
+    }
+
+    /// Advance by one tick. Returns the values of active entries.
+    pub fn tick(&mut self) -> Vec<(TweenId, T)> {
+        let Some(position) = self.timeline.advance() else {
+            return Vec::new();
+        };
+        self.sync(position);
+        self.active_values(position)
+    }
+
+    /// Advance by `n` ticks as one step; see [`Timeline::tick_n`].
+    pub fn tick_n(&mut self, n: u32) -> Vec<(TweenId, T)> {
+        self.timeline.markers.hold();
+        let mut position = Some(self.timeline.position());
+        for _ in 0..n {
+            position = self.timeline.advance();
+            if let Some(position) = position {
+                self.sync(position);
+            }
+        }
+        self.timeline.markers.release();
+        position.map_or_else(Vec::new, |position| self.active_values(position))
+    }
+
+    /// Remove an entry and its animation.
+    pub fn remove(&mut self, id: TweenId) -> Result<(), TweenError> {
+        let position = self.timeline.position_of(id).ok_or(TweenError::InvalidId)?;
+        self.timeline.remove(id)?;
+        self.children.remove(position);
+        Ok(())
+    }
+
+    /// Move an entry to start at `tick`, updating its animation to the current tick.
+    pub fn set_start(&mut self, id: TweenId, tick: u32) -> Result<(), TweenError> {
+        self.timeline.set_start(id, tick)?;
+        self.sync(self.timeline.position());
+        Ok(())
+    }
+
+    /// Current value of an entry's animation, whether or not it is active.
+    pub fn value(&self, id: TweenId) -> Option<T> {
+        self.timeline
+            .position_of(id)
+            .map(|index| self.children[index].animation.value())
+    }
+
+    /// Values of entries active at the current tick, as returned by [`tick`](Self::tick).
+    pub fn active(&self) -> Vec<(TweenId, T)> {
+        self.active_values(self.timeline.position())
+    }
+
+    /// Current values of every entry, in the order added.
+    pub fn values(&self) -> Vec<(TweenId, T)> {
+        self.timeline
+            .entries
+            .iter()
+            .zip(&self.children)
+            .map(|(entry, child)| (entry.id, child.animation.value()))
+            .collect()
+    }
+
+    /// Seek to a specific tick, moving every child to its local time there.
+    ///
+    /// Children step tick by tick toward their new local time. A child that cannot reverse
+    /// replays from its start to move backward, so scrubbing back costs up to its local time
+    /// in ticks.
+    pub fn seek(&mut self, tick: u32) {
+        self.timeline.seek(tick);
+        self.sync(self.timeline.position());
+    }
+
+    pub fn seek_label(&mut self, name: &str) -> Result<(), TweenError> {
+        self.timeline.seek_label(name)?;
+        self.sync(self.timeline.position());
+        Ok(())
+    }
+
+    /// The underlying timing, for entry and label lookups.
+    pub fn timeline(&self) -> &Timeline {
+        &self.timeline
+    }
+
+    pub fn total_duration(&self) -> u32 {
+        self.timeline.total_duration()
+    }
+
+    pub fn progress(&self) -> F {
+        self.timeline.progress()
+    }
+
+    pub fn direction(&self) -> PlayDirection {
+        self.timeline.direction()
+    }
+
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        self.timeline.set_direction(direction);
+    }
+
+    /// Flip direction mid-flight; children play back from where they are.
+    pub fn reverse(&mut self) {
+        self.timeline.reverse();
+    }
+
+    /// Reset and play a leg toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.timeline.rewind(direction);
+        self.sync(self.timeline.position());
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.timeline.is_finished()
+    }
+
+    pub fn state(&self) -> TweenState {
+        self.timeline.state()
+    }
+
+    pub fn reset(&mut self) {
+        self.timeline.reset();
+        for child in &mut self.children {
+            child.animation.reset();
+            child.local = 0;
+        }
+    }
+
+    fn insert<A>(&mut self, start: u32, animation: A) -> TweenId
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        let id = self.timeline.add(start, animation.total_duration());
+        self.children.push(TimelineChild {
+            animation: Box::new(animation),
+            local: 0,
+        });
+        let position = self.timeline.position();
+        if let (Some(entry), Some(child)) = (self.timeline.entries.last(), self.children.last_mut())
+        {
+            child.seek(local_target(entry, position));
+        }
+        id
+    }
+
+    fn resolve(&self, position: &TimelinePosition) -> Result<u32, TweenError> {
+        match position {
+            TimelinePosition::Tick(tick) => Ok(*tick),
+            TimelinePosition::Label(name) => self
+                .timeline
+                .label_tick(name)
+                .ok_or(TweenError::UnknownLabel),
+            TimelinePosition::End => Ok(self.timeline.total_duration()),
+        }
+    }
+
+    fn active_values(&self, position: u32) -> Vec<(TweenId, T)> {
+        self.timeline
+            .active_at::<F>(position)
+            .filter_map(|(id, _)| self.value(id).map(|value| (id, value)))
+            .collect()
+    }
+
+    /// Move every child to its local time at `position`.
+    fn sync(&mut self, position: u32) {
+        for (entry, child) in self.timeline.entries.iter().zip(&mut self.children) {
+            child.seek(local_target(entry, position));
+        }
+    }
+}
+
+/// An entry's local time at `position`, held at its end once it has finished.
+fn local_target(entry: &TimelineEntry, position: u32) -> u32 {
+    let local = position.saturating_sub(entry.start_tick);
+    if entry.duration == 0 {
+        local
+    } else {
+        local.min(entry.duration)
+    }
+}
+
+/// Children need not implement `Debug`, so only their local times are shown.
+impl<T, F: Float> fmt::Debug for AnimationTimeline<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimationTimeline")
+            .field("timeline", &self.timeline)
+            .field("children", &self.children)
+            .finish()
+    }
+}
+
+impl<T, F: Float> fmt::Debug for TimelineChild<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimelineChild")
+            .field("local", &self.local)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F: Float> TimelineChild<T, F> {
+    /// Step to `target` ticks of local time, reversing the animation to step backward when it
+    /// supports that, and replaying from the start otherwise. Costs one child tick per step,
+    /// or up to `target` ticks when it replays.
+    fn seek(&mut self, target: u32) {
+        if target == self.local {
+            return;
+        }
+        let direction = if target > self.local {
+            PlayDirection::Forward
+        } else {
+            PlayDirection::Backward
+        };
+        let steps = self.local.abs_diff(target);
+        if direction == PlayDirection::Backward && steps > target {
+            self.animation.reset();
+            self.local = 0;
+            return self.seek(target);
+        }
+        if self.animation.direction() != direction {
+            self.animation.reverse();
+            if self.animation.direction() != direction {
+                self.animation.reset();
+                self.local = 0;
+                return self.seek(target);
+            }
+        }
+        for _ in 0..steps {
+            self.animation.tick();
+        }
+        self.local = target;
+    }
+}
+
+impl<T, F: Float> Default for AnimationTimeline<T, F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::loop_mode::LoopMode;
-    use crate::timeline::Timeline;
+    use crate::error::TweenError;
+    use crate::loop_mode::{LoopMode, PlayDirection};
+    use crate::marker::SeekEvents;
+    use crate::timeline::{
+        ActiveLeaf, AnimationTimeline, BlendMode, NestedTimeline, Timeline, TimelinePosition,
+    };
+    use crate::tween::{Sequence, Tween};
 
     const EPS: f32 = 1e-4;
 
@@ -186,6 +1109,20 @@ mod tests {
         assert!(active.iter().any(|(id, _)| *id == id_b));
     }
 
+    #[test]
+    fn timeline_tick_into_and_active_iter() {
+        let mut timeline = Timeline::new();
+        let a = timeline.add(0, 4);
+        let b = timeline.add(2, 4);
+        let mut buffer = alloc::vec::Vec::new();
+        timeline.tick_into::<f32>(&mut buffer);
+        assert_eq!(buffer, [(a, 0.25)]);
+        buffer.clear();
+        timeline.tick_into::<f32>(&mut buffer);
+        assert_eq!(buffer, [(a, 0.5), (b, 0.0)]);
+        assert!(timeline.active_iter::<f32>().eq(buffer.iter().copied()));
+    }
+
     #[test]
     fn timeline_progress() {
         let mut timeline = Timeline::new();
@@ -200,6 +1137,19 @@ mod tests {
         assert!((progress - 0.5).abs() < EPS);
     }
 
+    #[test]
+    fn timeline_far_ticks_compare_exactly() {
+        let mut timeline = Timeline::new();
+        let id = timeline.add(16_777_217, 10);
+        assert_eq!(timeline.entries_at(16_777_216).count(), 0);
+        timeline.seek(16_777_216);
+        assert!(timeline.active::<f32>().is_empty());
+        assert!(timeline.blended::<f32>().is_empty());
+        assert_eq!(timeline.tick::<f32>(), [(id, 0.0)]);
+        timeline.seek(16_777_227);
+        assert_eq!(timeline.active::<f32>(), [(id, 1.0)]);
+    }
+
     #[test]
     fn timeline_seek() {
         let mut timeline = Timeline::new();
@@ -223,4 +1173,386 @@ mod tests {
         }
         assert!(!timeline.is_finished());
     }
+
+    #[test]
+    fn timeline_ping_pong_runs_backward() {
+        let mut timeline = Timeline::new().with_loop(LoopMode::PingPongCount(1));
+        let id = timeline.add(0, 10);
+        let progress = |active: alloc::vec::Vec<_>| {
+            active
+                .iter()
+                .find(|(entry_id, _)| *entry_id == id)
+                .map(|&(_, progress): &(_, f32)| progress)
+                .expect("entry should be active")
+        };
+        for _ in 0..10 {
+            let _ = timeline.tick::<f32>();
+        }
+        assert_eq!(timeline.direction(), PlayDirection::Backward);
+        assert!((progress(timeline.tick()) - 0.9).abs() < EPS);
+        for _ in 0..8 {
+            let _ = timeline.tick::<f32>();
+        }
+        assert!((progress(timeline.tick()) - 0.0).abs() < EPS);
+        assert!(timeline.is_finished());
+    }
+
+    #[test]
+    fn timeline_reverse_mid_flight() {
+        let mut timeline = Timeline::new();
+        let id = timeline.add(0, 10);
+        timeline.seek(4);
+        timeline.reverse();
+        let active = timeline.tick::<f32>();
+        assert_eq!(active, [(id, 0.3)]);
+
+        timeline.seek(8);
+        timeline.set_direction(PlayDirection::Forward);
+        let active = timeline.tick::<f32>();
+        assert!((active[0].1 - 0.9).abs() < EPS);
+    }
+
+    #[test]
+    fn timeline_labels() {
+        let mut timeline = Timeline::new();
+        let id = timeline.add(0, 10);
+        timeline.add_label("hit", 4);
+        assert_eq!(timeline.label_tick("hit"), Some(4));
+        timeline.seek_label("hit").unwrap();
+        assert_eq!(timeline.tick::<f32>(), [(id, 0.5)]);
+        assert_eq!(timeline.seek_label("miss"), Err(TweenError::UnknownLabel));
+    }
+
+    #[test]
+    fn animation_timeline_drives_children() {
+        let mut timeline = AnimationTimeline::new();
+        let a = timeline.add(Tween::new(0.0f32, 10.0, 10));
+        timeline.add_label("mid", 5).unwrap();
+        let b = timeline
+            .add_at("mid", Tween::new(100.0f32, 200.0, 10))
+            .unwrap();
+        let c = timeline.add(
+            Sequence::new()
+                .push(Tween::new(0.0f32, 1.0, 1))
+                .push(Tween::new(1.0, 2.0, 1)),
+        );
+        assert_eq!(timeline.timeline().label_tick("mid"), Some(5));
+        assert_eq!(timeline.total_duration(), 17);
+        assert_eq!(
+            timeline.add_at("missing", Tween::new(0.0f32, 1.0, 1)),
+            Err(TweenError::UnknownLabel)
+        );
+
+        for _ in 0..4 {
+            assert_eq!(timeline.tick().len(), 1);
+        }
+        assert_eq!(timeline.tick(), [(a, 5.0), (b, 100.0)]);
+        for _ in 0..5 {
+            timeline.tick();
+        }
+        assert_eq!(timeline.value(a), Some(10.0));
+        assert_eq!(timeline.value(b), Some(150.0));
+        assert_eq!(timeline.value(c), Some(0.0));
+        while !timeline.is_finished() {
+            timeline.tick();
+        }
+        assert_eq!(timeline.values(), [(a, 10.0), (b, 200.0), (c, 2.0)]);
+    }
+
+    #[test]
+    fn animation_timeline_seek_and_reverse() {
+        let mut timeline = AnimationTimeline::new();
+        let a = timeline
+            .add_at(TimelinePosition::Tick(0), Tween::new(0.0f32, 10.0, 10))
+            .unwrap();
+        let b = timeline.add_at(5, Tween::new(100.0f32, 200.0, 10)).unwrap();
+
+        timeline.seek(12);
+        assert_eq!(timeline.values(), [(a, 10.0), (b, 170.0)]);
+        timeline.seek(3);
+        assert_eq!(timeline.values(), [(a, 3.0), (b, 100.0)]);
+
+        timeline.seek(8);
+        timeline.reverse();
+        assert_eq!(timeline.tick(), [(a, 7.0), (b, 120.0)]);
+        timeline.set_direction(PlayDirection::Forward);
+        assert_eq!(timeline.tick(), [(a, 8.0), (b, 130.0)]);
+    }
+
+    #[test]
+    fn animation_timeline_ping_pong() {
+        let mut timeline = AnimationTimeline::new().with_loop(LoopMode::PingPongCount(1));
+        let id = timeline.add(Tween::new(0.0f32, 4.0, 4));
+        let mut values = [0.0f32; 8];
+        for value in &mut values {
+            *value = timeline.tick()[0].1;
+        }
+        assert_eq!(values, [1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0, 0.0]);
+        assert!(timeline.is_finished());
+        assert_eq!(timeline.value(id), Some(0.0));
+    }
+
+    #[test]
+    fn nested_timeline_time_scale() {
+        let mut child = Timeline::new();
+        let leaf = child.add(0, 10);
+        let mut parent = Timeline::new();
+        let fast = parent.add_timeline(5, NestedTimeline::new(child.clone()).with_time_scale(2.0));
+        let slow = parent.add_timeline(0, NestedTimeline::new(child).with_time_scale(0.5));
+        assert_eq!(parent.nested(fast).map(NestedTimeline::span), Some(5));
+        assert_eq!(parent.total_duration(), 20);
+
+        parent.seek(7);
+        let leaves = parent.active_leaves::<f32>();
+        assert_eq!(
+            leaves,
+            [
+                ActiveLeaf {
+                    path: alloc::vec![fast, leaf],
+                    progress: 0.4,
+                },
+                ActiveLeaf {
+                    path: alloc::vec![slow, leaf],
+                    progress: 0.35,
+                },
+            ]
+        );
+        let active = parent.tick::<f32>();
+        assert!((active[0].1 - 0.6).abs() < EPS);
+    }
+
+    #[test]
+    fn nested_timeline_reverse_clip_and_loops() {
+        let mut child = Timeline::new();
+        child.add(0, 10);
+        let mut parent = Timeline::new();
+        parent.add_timeline(
+            0,
+            NestedTimeline::new(child)
+                .with_clip(2, 8)
+                .with_time_scale(-1.0),
+        );
+        assert_eq!(parent.total_duration(), 6);
+        parent.seek(1);
+        assert!((parent.active_leaves::<f32>()[0].progress - 0.7).abs() < EPS);
+        parent.seek(6);
+        assert!((parent.active_leaves::<f32>()[0].progress - 0.2).abs() < EPS);
+
+        let mut child = Timeline::new().with_loop(LoopMode::PingPongCount(1));
+        child.add(0, 4);
+        let mut parent = Timeline::new();
+        parent.add_timeline(0, NestedTimeline::new(child).with_time_scale(0.5));
+        assert_eq!(parent.total_duration(), 16);
+        parent.seek(10);
+        assert!((parent.active_leaves::<f32>()[0].progress - 0.75).abs() < EPS);
+    }
+
+    #[test]
+    fn seeking_parent_scrubs_descendants() {
+        let mut grandchild = Timeline::new();
+        let leaf = grandchild.add(0, 8);
+        let mut child = Timeline::new();
+        let inner = child.add_timeline(2, NestedTimeline::new(grandchild).with_time_scale(2.0));
+        let mut parent = Timeline::new();
+        let outer = parent.add_timeline(10, NestedTimeline::new(child));
+        assert_eq!(parent.total_duration(), 16);
+
+        for tick in [13, 15, 11, 14] {
+            parent.seek(tick);
+            let leaves = parent.active_leaves::<f32>();
+            if tick < 12 {
+                assert!(leaves.is_empty());
+                continue;
+            }
+            let expected = (tick - 12) as f32 * 2.0 / 8.0;
+            assert_eq!(leaves[0].path, [outer, inner, leaf]);
+            assert!((leaves[0].progress - expected).abs() < EPS);
+        }
+    }
+
+    #[test]
+    fn timeline_markers_fire_on_every_loop() {
+        let mut timeline = Timeline::new().with_loop(LoopMode::Count(2));
+        timeline.add(0, 10);
+        timeline.add_marker(0, 0);
+        timeline.add_marker(5, 1);
+        timeline.add_marker(10, 2);
+        let mut fired = alloc::vec::Vec::new();
+        for tick in 1..=20 {
+            let _ = timeline.tick::<f32>();
+            fired.extend(timeline.fired_events().iter().map(|m| (tick, m.event)));
+        }
+        assert_eq!(fired, [(1, 0), (5, 1), (10, 2), (11, 0), (15, 1), (20, 2)]);
+        assert!(timeline.is_finished());
+        let _ = timeline.tick::<f32>();
+        assert!(timeline.fired_events().is_empty());
+    }
+
+    #[test]
+    fn timeline_markers_ping_pong_and_seek() {
+        let mut timeline = Timeline::new()
+            .with_loop(LoopMode::PingPongCount(1))
+            .with_seek_events(SeekEvents::Fire);
+        timeline.add(0, 10);
+        timeline.add_marker(0, 0);
+        timeline.add_marker(5, 1);
+        timeline.add_marker(10, 2);
+        let mut fired = alloc::vec::Vec::new();
+        while !timeline.is_finished() {
+            let _ = timeline.tick::<f32>();
+            fired.extend(timeline.fired_events().iter().map(|m| m.event));
+        }
+        assert_eq!(fired, [0, 1, 2, 1, 0]);
+
+        timeline.reset();
+        timeline.seek(10);
+        let events: alloc::vec::Vec<u32> =
+            timeline.fired_events().iter().map(|m| m.event).collect();
+        assert_eq!(events, [0, 1, 2]);
+        timeline.seek(4);
+        assert_eq!(timeline.fired_events(), [timeline.markers()[1]]);
+    }
+
+    #[test]
+    fn animation_timeline_reports_markers() {
+        let mut timeline = AnimationTimeline::new();
+        timeline.add(Tween::new(0.0f32, 1.0, 4));
+        timeline.add_marker(2, 7);
+        timeline.tick();
+        assert!(timeline.fired_events().is_empty());
+        timeline.tick();
+        assert_eq!(timeline.fired_events()[0].event, 7);
+    }
+
+    #[test]
+    fn timeline_edit_and_query_entries() {
+        let mut timeline = Timeline::new();
+        let a = timeline.add(0, 10);
+        let b = timeline.add(5, 10);
+        let c = timeline.add(20, 5);
+        assert_eq!(timeline.entry(b).map(|e| e.start_tick), Some(5));
+
+        timeline.set_start(b, 8).unwrap();
+        let ids = |entries: &mut dyn Iterator<Item = &super::TimelineEntry>| {
+            entries
+                .map(|entry| entry.id)
+                .collect::<alloc::vec::Vec<_>>()
+        };
+        assert_eq!(ids(&mut timeline.entries_at(9)), [a, b]);
+        timeline.set_duration(a, 4).unwrap();
+        assert_eq!(ids(&mut timeline.entries_at(9)), [b]);
+        assert_eq!(ids(&mut timeline.entries_in_range(15..21)), [b, c]);
+        assert_eq!(ids(&mut timeline.entries_in_range(..=4)), [a]);
+        assert_eq!(ids(&mut timeline.entries_in_range(19..19)), []);
+        assert_eq!(timeline.total_duration(), 25);
+    }
+
+    #[test]
+    fn timeline_stale_ids_are_rejected() {
+        let mut timeline = Timeline::new();
+        let a = timeline.add(0, 10);
+        let b = timeline.add(5, 10);
+        let removed = timeline.remove(a).unwrap();
+        assert_eq!((removed.id, removed.start_tick), (a, 0));
+        assert!(!timeline.contains(a));
+        assert_eq!(timeline.remove(a).unwrap_err(), TweenError::InvalidId);
+
+        let c = timeline.add(1, 2);
+        assert_eq!(c.index(), a.index());
+        assert_ne!(c, a);
+        assert!(timeline.entry(a).is_none());
+        assert_eq!(timeline.set_start(a, 3), Err(TweenError::InvalidId));
+        assert_eq!(timeline.set_duration(a, 3), Err(TweenError::InvalidId));
+        assert_eq!(timeline.entry(c).map(|e| e.start_tick), Some(1));
+        assert_eq!(timeline.entry(b).map(|e| e.start_tick), Some(5));
+    }
+
+    #[test]
+    fn animation_timeline_remove_and_move() {
+        let mut timeline = AnimationTimeline::new();
+        let a = timeline.add(Tween::new(0.0f32, 10.0, 10));
+        let b = timeline.add_at(0, Tween::new(0.0f32, 4.0, 4)).unwrap();
+        timeline.seek(3);
+        timeline.remove(a).unwrap();
+        assert_eq!(timeline.value(a), None);
+        assert_eq!(timeline.value(b), Some(3.0));
+        assert_eq!(timeline.remove(a), Err(TweenError::InvalidId));
+
+        timeline.set_start(b, 2).unwrap();
+        assert_eq!(timeline.value(b), Some(1.0));
+        assert_eq!(timeline.tick(), [(b, 2.0)]);
+    }
+
+    #[test]
+    fn animation_timeline_holds_values_without_debug() {
+        #[derive(Clone, Copy, PartialEq)]
+        struct Level(f32);
+
+        impl crate::lerp::Lerp<f32> for Level {
+            fn lerp(&self, other: &Self, t: f32) -> Self {
+                Level(self.0.lerp(&other.0, t))
+            }
+        }
+
+        let mut timeline = AnimationTimeline::new();
+        let id = timeline.add(Tween::new(Level(0.0), Level(4.0), 4));
+        timeline.seek(2);
+        assert!(timeline.value(id) == Some(Level(2.0)));
+        assert!(alloc::format!("{timeline:?}").starts_with("AnimationTimeline"));
+    }
+
+    #[test]
+    fn timeline_track_blend_modes() {
+        let mut timeline = Timeline::new()
+            .with_blend_mode(1, BlendMode::Crossfade)
+            .with_blend_mode(2, BlendMode::Additive);
+        let a = timeline.add(0, 10);
+        let b = timeline.add(5, 10);
+        let fade_out = timeline.add_to_track(1, 0, 10);
+        let fade_in = timeline.add_to_track(1, 6, 10);
+        timeline.add_to_track(2, 0, 10);
+        timeline.add_to_track(2, 5, 10);
+        assert_eq!(timeline.blend_mode(0), BlendMode::Override);
+        assert_eq!(timeline.blend_mode(1), BlendMode::Crossfade);
+
+        let weights = |timeline: &Timeline| -> alloc::vec::Vec<(u32, f32)> {
+            timeline
+                .blended::<f32>()
+                .iter()
+                .map(|entry| (entry.track, entry.weight))
+                .collect()
+        };
+        timeline.seek(3);
+        assert_eq!(weights(&timeline), [(0, 1.0), (1, 1.0), (2, 1.0)]);
+
+        timeline.seek(7);
+        let blended = timeline.tick_blended::<f32>();
+        let find = |id| blended.iter().find(|entry| entry.id == id).unwrap();
+        assert_eq!((find(a).weight, find(b).weight), (0.0, 1.0));
+        assert_eq!((find(fade_out).weight, find(fade_in).weight), (0.5, 0.5));
+        assert!((find(fade_in).progress - 0.2).abs() < EPS);
+        assert_eq!(weights(&timeline)[4..], [(2, 1.0), (2, 1.0)]);
+
+        timeline.seek(10);
+        let blended = timeline.blended::<f32>();
+        let find = |id| blended.iter().find(|entry| entry.id == id).unwrap();
+        assert_eq!((find(fade_out).weight, find(fade_in).weight), (0.0, 1.0));
+
+        timeline.set_track(b, 3).unwrap();
+        timeline.seek(7);
+        assert_eq!(timeline.blended::<f32>()[0].weight, 1.0);
+    }
+
+    #[test]
+    fn crossfade_weights_sum_to_one() {
+        let mut timeline = Timeline::new().with_blend_mode(0, BlendMode::Crossfade);
+        timeline.add(0, 10);
+        timeline.add(4, 10);
+        timeline.add(8, 10);
+        for tick in 0..=18 {
+            timeline.seek(tick);
+            let total: f32 = timeline.blended::<f32>().iter().map(|e| e.weight).sum();
+            assert!((total - 1.0).abs() < EPS, "tick {tick}: {total}");
+        }
+    }
 }
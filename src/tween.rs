@@ -1,14 +1,50 @@
-use alloc::vec::Vec;
-
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::animation::{Animation, DynAnimation};
+#[cfg(feature = "alloc")]
+use crate::bake::record;
 use crate::easing::Easing;
+#[cfg(feature = "alloc")]
+use crate::error::TweenError;
 use crate::float::Float;
+#[cfg(feature = "alloc")]
+use crate::keyframes::Keyframes;
 use crate::lerp::Lerp;
 use crate::loop_mode::{LoopMode, PlayDirection};
+#[cfg(feature = "alloc")]
+use crate::stagger::StaggerPattern;
 use crate::state::TweenState;
 
-/// Opaque identifier for a tween in a Timeline.
+/// Opaque identifier for a tween in a Timeline or Animator.
+///
+/// Ids are generational: once an entry is removed its slot may be reused, but the new entry gets
+/// a new generation, so stale ids are rejected instead of reaching it. Ids do not record which
+/// timeline or animator issued them; using one with another container is not detected.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct TweenId(pub u32);
+pub struct TweenId {
+    index: u32,
+    generation: u32,
+}
+
+impl TweenId {
+    pub const fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// Slot the id refers to.
+    pub const fn index(self) -> u32 {
+        self.index
+    }
+
+    /// Times the slot had been reused when the id was issued.
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+}
 
 /// A single from-to animation with easing, delay, and looping.
 #[derive(Clone, Debug)]
@@ -163,6 +199,36 @@ impl<T: Lerp<F> + Clone, F: Float> Tween<T, F> {
         }
     }
 
+    /// Current playback direction.
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current value.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight, continuing from the current value. A finished tween plays
+    /// back the way it came.
+    pub fn reverse(&mut self) {
+        self.direction = self.direction.reversed();
+        if self.delay_remaining == 0 {
+            self.elapsed = self.duration - self.elapsed.min(self.duration);
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a leg toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+    }
+
     /// Change target value mid-flight.
     pub fn set_target(&mut self, new_to: T) {
         self.to = new_to;
@@ -184,6 +250,16 @@ impl<T: Lerp<F> + Clone, F: Float> Tween<T, F> {
         self.loops_completed
     }
 
+    /// Bake one pass from the start, delay included, keeping every `interval`-th tick.
+    #[cfg(feature = "alloc")]
+    pub fn bake(&self, interval: u32) -> Keyframes<T, F> {
+        let mut tween = self.clone();
+        tween.reset();
+        record(tween.value(), interval, tween.total_duration(), || {
+            (tween.tick(), tween.is_finished())
+        })
+    }
+
     fn on_iteration_complete(&mut self) {
         match self.loop_mode {
             LoopMode::Once => {
@@ -195,7 +271,6 @@ impl<T: Lerp<F> + Clone, F: Float> Tween<T, F> {
                     self.state = TweenState::Finished;
                 } else {
                     self.elapsed = 0;
-                    self.direction = PlayDirection::Forward;
                 }
             }
             LoopMode::Infinite => {
@@ -205,10 +280,7 @@ impl<T: Lerp<F> + Clone, F: Float> Tween<T, F> {
             LoopMode::PingPong => {
                 self.loops_completed += 1;
                 self.elapsed = 0;
-                self.direction = match self.direction {
-                    PlayDirection::Forward => PlayDirection::Backward,
-                    PlayDirection::Backward => PlayDirection::Forward,
-                };
+                self.direction = self.direction.reversed();
             }
             LoopMode::PingPongCount(count) => {
                 self.loops_completed += 1;
@@ -217,45 +289,96 @@ impl<T: Lerp<F> + Clone, F: Float> Tween<T, F> {
                     self.state = TweenState::Finished;
                 } else {
                     self.elapsed = 0;
-                    self.direction = match self.direction {
-                        PlayDirection::Forward => PlayDirection::Backward,
-                        PlayDirection::Backward => PlayDirection::Forward,
-                    };
+                    self.direction = self.direction.reversed();
                 }
             }
         }
     }
 }
 
-/// Plays tweens one after another in order.
-#[derive(Clone, Debug)]
-pub struct Sequence<T: Lerp<F>, F: Float> {
-    tweens: Vec<Tween<T, F>>,
+/// Plays animations one after another in order.
+///
+/// Children can be any [`Animation`] producing `T`: tweens, keyframes, springs or other
+/// compositions. Each child may start some ticks after the previous one finishes (a gap) or
+/// before it ends (an overlap); while children overlap, the later one's value is shown.
+/// Labels name the start of a child so playback can jump there.
+///
+/// Each child is boxed, so children must be `'static` and cost one allocation each; a `Tween`
+/// of a borrowed `Typewriter`, for example, cannot be pushed. [`FixedSequence`] holds children
+/// of one type inline, borrowed or not. Children need not implement `Debug`; the sequence's
+/// `Debug` output shows how many there are.
+///
+/// [`FixedSequence`]: crate::FixedSequence
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Sequence<T, F: Float> {
+    children: Vec<Box<dyn DynAnimation<F, T>>>,
+    /// Ticks between each child and the one before it; negative values overlap.
+    offsets: Vec<i32>,
+    labels: Vec<(String, usize)>,
     current_index: usize,
+    /// Children started before the current one, in play order, that are still running.
+    overlapped: Vec<usize>,
+    /// Ticks since the current child started.
+    local: u32,
+    /// Gap ticks left before the child at the given index starts.
+    hold: Option<(u32, usize)>,
+    elapsed: u32,
     state: TweenState,
     loop_mode: LoopMode,
     loops_completed: u32,
+    direction: PlayDirection,
 }
 
-impl<T: Lerp<F> + Clone, F: Float> Sequence<T, F> {
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Sequence<T, F> {
     pub fn new() -> Self {
         Self {
-            tweens: Vec::new(),
+            children: Vec::new(),
+            offsets: Vec::new(),
+            labels: Vec::new(),
             current_index: 0,
+            overlapped: Vec::new(),
+            local: 0,
+            hold: None,
+            elapsed: 0,
             state: TweenState::Idle,
             loop_mode: LoopMode::Once,
             loops_completed: 0,
+            direction: PlayDirection::Forward,
         }
     }
 
-    pub fn push(mut self, tween: Tween<T, F>) -> Self {
-        self.tweens.push(tween);
+    pub fn push<A>(self, animation: A) -> Self
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        self.push_offset(animation, 0)
+    }
+
+    /// Append a child starting `offset` ticks after the previous child ends: positive values
+    /// leave a gap, negative values overlap the previous child. Ignored for the first child.
+    ///
+    /// Overlaps are measured from the previous child's nominal duration; one of unknown
+    /// duration, such as a spring, is always waited for.
+    pub fn push_offset<A>(mut self, animation: A, offset: i32) -> Self
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        self.children.push(Box::new(animation));
+        self.offsets.push(offset);
         if self.state == TweenState::Idle {
             self.state = TweenState::Playing;
         }
         self
     }
 
+    /// Name the start of the next child pushed, or the end if none follows.
+    pub fn with_label(mut self, name: impl Into<String>) -> Self {
+        self.labels.push((name.into(), self.children.len()));
+        self
+    }
+
     pub fn with_loop(mut self, mode: LoopMode) -> Self {
         self.loop_mode = mode;
         self
@@ -263,34 +386,172 @@ impl<T: Lerp<F> + Clone, F: Float> Sequence<T, F> {
 
     pub fn tick(&mut self) -> T {
         assert!(
-            !self.tweens.is_empty(),
-            "Sequence requires at least one tween"
+            !self.children.is_empty(),
+            "Sequence requires at least one animation"
         );
         if self.state != TweenState::Playing {
             return self.value();
         }
 
-        let value = self.tweens[self.current_index].tick();
-        if self.tweens[self.current_index].is_finished() {
-            if self.current_index + 1 < self.tweens.len() {
-                self.current_index += 1;
+        self.elapsed = self.elapsed.saturating_add(1);
+        if let Some((ticks, index)) = self.hold {
+            let value = self.value();
+            if ticks > 1 {
+                self.hold = Some((ticks - 1, index));
             } else {
-                self.on_sequence_complete();
+                self.hold = None;
+                self.begin(index);
+                self.advance();
             }
+            return value;
+        }
+
+        for i in 0..self.overlapped.len() {
+            self.children[self.overlapped[i]].tick();
         }
+        self.children[self.current_index].tick();
+        self.local = self.local.saturating_add(1);
+        let value = self.value();
+        self.overlapped
+            .retain(|&index| !self.children[index].is_finished());
+        self.advance();
         value
     }
 
+    /// Current playback direction. Backward legs play the children last to first, each in
+    /// reverse, with gaps and overlaps mirrored; a child's delay still comes before it.
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Play toward `direction`'s end from the current value.
+    pub fn set_direction(&mut self, direction: PlayDirection) {
+        if direction != self.direction {
+            self.reverse();
+        }
+    }
+
+    /// Flip direction mid-flight, continuing from the current value. A finished sequence plays
+    /// back the way it came.
+    pub fn reverse(&mut self) {
+        if self.children.is_empty() {
+            return;
+        }
+        let total = self.total_duration();
+        self.direction = self.direction.reversed();
+        self.elapsed = total - self.elapsed.min(total);
+
+        if let Some((ticks, next)) = self.hold {
+            // Wait out the part of the gap already played, then replay the child before it.
+            let gap = self.offset_between(self.current_index, next).unsigned_abs();
+            let waited = gap.saturating_sub(ticks);
+            if waited == 0 {
+                self.hold = None;
+                self.begin(self.current_index);
+            } else {
+                self.hold = Some((waited, self.current_index));
+            }
+        } else {
+            self.overlapped.push(self.current_index);
+            for &index in &self.overlapped {
+                self.children[index].reverse();
+            }
+            // The child furthest along in the new direction becomes current.
+            let newest = match self.direction {
+                PlayDirection::Forward => self.overlapped.iter().copied().max(),
+                PlayDirection::Backward => self.overlapped.iter().copied().min(),
+            };
+            self.current_index = newest.unwrap_or(self.current_index);
+            let current = self.current_index;
+            self.overlapped.retain(|&index| index != current);
+
+            let child = &self.children[current];
+            let duration = child.total_duration();
+            let position = (child.progress().to_f32() * duration as f32 + 0.5) as u32;
+            self.local = match self.direction {
+                PlayDirection::Forward => position,
+                PlayDirection::Backward => duration - position.min(duration),
+            };
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a leg toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        if !self.children.is_empty() {
+            self.direction = direction;
+            self.restart();
+        }
+    }
+
+    /// Value shown now: the latest child in sequence order that has started.
     pub fn value(&self) -> T {
         assert!(
-            !self.tweens.is_empty(),
-            "Sequence requires at least one tween"
+            !self.children.is_empty(),
+            "Sequence requires at least one animation"
         );
-        self.tweens[self.current_index].value()
+        let visible = self
+            .overlapped
+            .iter()
+            .copied()
+            .fold(self.current_index, usize::max);
+        self.children[visible].value()
+    }
+
+    /// Value `tick` ticks after the start of the first pass, without moving the playhead.
+    ///
+    /// Clones every child and replays from the start like [`seek`](Self::seek), so each call
+    /// allocates and costs O(`tick`) child ticks; bake the sequence to sample it repeatedly.
+    pub fn value_at(&self, tick: u32) -> T
+    where
+        T: Clone,
+    {
+        let mut sequence = self.clone();
+        sequence.seek(tick);
+        sequence.value()
+    }
+
+    /// Jump to `tick` ticks after the start of the first pass by replaying up to it.
+    ///
+    /// Children cannot jump on their own, so this resets and ticks the whole sequence `tick`
+    /// times: scrubbing costs O(`tick`) per call, backward as well as forward.
+    pub fn seek(&mut self, tick: u32) {
+        self.reset();
+        for _ in 0..tick {
+            if self.state != TweenState::Playing {
+                break;
+            }
+            self.tick();
+        }
     }
 
+    /// Tick at which the label's child starts in a forward pass.
+    pub fn label_tick(&self, name: &str) -> Option<u32> {
+        self.labels
+            .iter()
+            .find(|(label, _)| label == name)
+            .map(|&(_, index)| self.start_of(index))
+    }
+
+    /// Jump to a label from the start of the first pass.
+    pub fn seek_label(&mut self, name: &str) -> Result<(), TweenError> {
+        let tick = self.label_tick(name).ok_or(TweenError::UnknownLabel)?;
+        self.seek(tick);
+        Ok(())
+    }
+
+    /// End of the last child, with gaps and overlaps applied.
     pub fn total_duration(&self) -> u32 {
-        self.tweens.iter().map(Tween::total_duration).sum()
+        (0..self.children.len())
+            .map(|index| {
+                self.start_of(index)
+                    .saturating_add(self.children[index].total_duration())
+            })
+            .max()
+            .unwrap_or(0)
     }
 
     pub fn progress(&self) -> F {
@@ -298,33 +559,111 @@ impl<T: Lerp<F> + Clone, F: Float> Sequence<T, F> {
         if total == 0 {
             return F::one();
         }
-        let mut elapsed_ticks = 0u32;
-        for (idx, tween) in self.tweens.iter().enumerate() {
-            if idx < self.current_index {
-                elapsed_ticks = elapsed_ticks.saturating_add(tween.total_duration());
-            } else if idx == self.current_index {
-                let local = tween.progress().to_f32() * tween.total_duration() as f32;
-                elapsed_ticks = elapsed_ticks.saturating_add(local as u32);
-            }
-        }
-        F::from_f32(elapsed_ticks as f32 / total as f32).clamp(F::zero(), F::one())
+        let position = match self.direction {
+            PlayDirection::Forward => self.elapsed,
+            PlayDirection::Backward => total.saturating_sub(self.elapsed),
+        };
+        F::from_f32(position.min(total) as f32 / total as f32)
     }
 
     pub fn is_finished(&self) -> bool {
         self.state == TweenState::Finished
     }
 
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
+    /// Bake one pass from the start, keeping every `interval`-th tick.
+    pub fn bake(&self, interval: u32) -> Keyframes<T, F>
+    where
+        T: Lerp<F> + Clone,
+    {
+        let mut sequence = self.clone();
+        sequence.reset();
+        record(
+            sequence.value(),
+            interval,
+            sequence.total_duration(),
+            || (sequence.tick(), sequence.is_finished()),
+        )
+    }
+
     pub fn reset(&mut self) {
-        for tween in &mut self.tweens {
-            tween.reset();
+        for child in &mut self.children {
+            child.reset();
         }
         self.current_index = 0;
-        self.state = if self.tweens.is_empty() {
+        self.overlapped.clear();
+        self.local = 0;
+        self.hold = None;
+        self.elapsed = 0;
+        self.direction = PlayDirection::Forward;
+        self.state = if self.children.is_empty() {
             TweenState::Idle
         } else {
             TweenState::Playing
         };
         self.loops_completed = 0;
+        if !self.children.is_empty() {
+            self.advance();
+        }
+    }
+
+    /// Start the next child once the current one is far enough along, or complete the pass.
+    fn advance(&mut self) {
+        loop {
+            let current = &self.children[self.current_index];
+            let finished = current.is_finished();
+            let Some(next) = self.next_index() else {
+                if finished && self.overlapped.is_empty() {
+                    self.on_sequence_complete();
+                }
+                return;
+            };
+            let offset = self.offset_between(self.current_index, next);
+            let duration = current.total_duration();
+            if offset < 0 && !finished && duration > 0 {
+                let lead = duration.saturating_sub(offset.unsigned_abs());
+                if self.local < lead {
+                    return;
+                }
+                self.overlapped.push(self.current_index);
+            } else if !finished {
+                return;
+            } else if offset > 0 {
+                self.hold = Some((offset.unsigned_abs(), next));
+                return;
+            }
+            self.begin(next);
+        }
+    }
+
+    fn next_index(&self) -> Option<usize> {
+        match self.direction {
+            PlayDirection::Forward => {
+                Some(self.current_index + 1).filter(|&next| next < self.children.len())
+            }
+            PlayDirection::Backward => self.current_index.checked_sub(1),
+        }
+    }
+
+    /// Offset between two neighbouring children, whichever order they play in.
+    fn offset_between(&self, a: usize, b: usize) -> i32 {
+        self.offsets[a.max(b)]
+    }
+
+    /// Start tick of child `index` in a forward pass, from nominal durations.
+    fn start_of(&self, index: usize) -> u32 {
+        let mut start = 0u32;
+        for i in 1..=index.min(self.children.len().saturating_sub(1)) {
+            let end = start.saturating_add(self.children[i - 1].total_duration());
+            start = end.saturating_add_signed(self.offsets[i]).max(start);
+        }
+        if index >= self.children.len() {
+            return self.total_duration();
+        }
+        start
     }
 
     fn on_sequence_complete(&mut self) {
@@ -340,8 +679,13 @@ impl<T: Lerp<F> + Clone, F: Float> Sequence<T, F> {
                     self.restart();
                 }
             }
-            LoopMode::Infinite | LoopMode::PingPong => {
+            LoopMode::Infinite => {
+                self.loops_completed += 1;
+                self.restart();
+            }
+            LoopMode::PingPong => {
                 self.loops_completed += 1;
+                self.direction = self.direction.reversed();
                 self.restart();
             }
             LoopMode::PingPongCount(count) => {
@@ -350,6 +694,7 @@ impl<T: Lerp<F> + Clone, F: Float> Sequence<T, F> {
                 if max_legs == 0 || self.loops_completed >= max_legs {
                     self.state = TweenState::Finished;
                 } else {
+                    self.direction = self.direction.reversed();
                     self.restart();
                 }
             }
@@ -357,37 +702,85 @@ impl<T: Lerp<F> + Clone, F: Float> Sequence<T, F> {
     }
 
     fn restart(&mut self) {
-        for tween in &mut self.tweens {
-            tween.reset();
-        }
-        self.current_index = 0;
+        let first = match self.direction {
+            PlayDirection::Forward => 0,
+            PlayDirection::Backward => self.children.len() - 1,
+        };
+        self.overlapped.clear();
+        self.hold = None;
+        self.elapsed = 0;
         self.state = TweenState::Playing;
+        self.begin(first);
+        self.advance();
+    }
+
+    /// Make `index` the current child, starting it from its beginning in our direction.
+    fn begin(&mut self, index: usize) {
+        self.current_index = index;
+        self.local = 0;
+        self.children[index].rewind(self.direction);
     }
 }
 
-impl<T: Lerp<F> + Clone, F: Float> Default for Sequence<T, F> {
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Default for Sequence<T, F> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Plays multiple tweens simultaneously.
-#[derive(Clone, Debug)]
-pub struct Parallel<T: Lerp<F>, F: Float> {
-    tweens: Vec<Tween<T, F>>,
+#[cfg(feature = "alloc")]
+impl<T, F: Float> fmt::Debug for Sequence<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sequence")
+            .field("children", &self.children.len())
+            .field("offsets", &self.offsets)
+            .field("labels", &self.labels)
+            .field("current_index", &self.current_index)
+            .field("overlapped", &self.overlapped)
+            .field("local", &self.local)
+            .field("hold", &self.hold)
+            .field("elapsed", &self.elapsed)
+            .field("state", &self.state)
+            .field("loop_mode", &self.loop_mode)
+            .field("loops_completed", &self.loops_completed)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+/// Plays multiple animations simultaneously.
+///
+/// Children are boxed like [`Sequence`]'s, so they must be `'static`; [`FixedParallel`] holds
+/// them inline instead. Backward passes end every child together, so shorter children start
+/// late rather than finish early.
+///
+/// [`FixedParallel`]: crate::FixedParallel
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Parallel<T, F: Float> {
+    children: Vec<Box<dyn DynAnimation<F, T>>>,
+    elapsed: u32,
     state: TweenState,
+    direction: PlayDirection,
 }
 
-impl<T: Lerp<F> + Clone, F: Float> Parallel<T, F> {
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Parallel<T, F> {
     pub fn new() -> Self {
         Self {
-            tweens: Vec::new(),
+            children: Vec::new(),
+            elapsed: 0,
             state: TweenState::Idle,
+            direction: PlayDirection::Forward,
         }
     }
 
-    pub fn push(mut self, tween: Tween<T, F>) -> Self {
-        self.tweens.push(tween);
+    pub fn push<A>(mut self, animation: A) -> Self
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        self.children.push(Box::new(animation));
         if self.state == TweenState::Idle {
             self.state = TweenState::Playing;
         }
@@ -395,61 +788,190 @@ impl<T: Lerp<F> + Clone, F: Float> Parallel<T, F> {
     }
 
     pub fn tick(&mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.children.len());
+        self.tick_into(&mut values);
+        values
+    }
+
+    /// [`tick`](Self::tick) that appends the values to `out`, e.g. a reused buffer, instead of
+    /// allocating.
+    pub fn tick_into(&mut self, out: &mut impl Extend<T>) {
         if self.state != TweenState::Playing {
-            return self.values();
+            out.extend(self.values_iter());
+            return;
         }
 
-        let values: Vec<T> = self.tweens.iter_mut().map(Tween::tick).collect();
-        if self.tweens.iter().all(Tween::is_finished) {
+        let (elapsed, total, direction) = (self.elapsed, self.total_duration(), self.direction);
+        out.extend(self.children.iter_mut().map(|child| {
+            let start = direction.pass_start(0, child.total_duration(), total);
+            if start == elapsed {
+                child.rewind(direction);
+            }
+            if start <= elapsed {
+                child.tick()
+            } else {
+                child.value()
+            }
+        }));
+        self.elapsed = self.elapsed.saturating_add(1);
+        let done = self.children.iter().all(|child| {
+            direction.pass_start(0, child.total_duration(), total) <= elapsed && child.is_finished()
+        });
+        if done {
             self.state = TweenState::Finished;
         }
-        values
     }
 
     pub fn values(&self) -> Vec<T> {
-        self.tweens.iter().map(Tween::value).collect()
+        self.values_iter().collect()
+    }
+
+    /// Current values, without collecting them.
+    pub fn values_iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.children.iter().map(|child| child.value())
     }
 
     pub fn is_finished(&self) -> bool {
         self.state == TweenState::Finished
     }
 
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
     pub fn total_duration(&self) -> u32 {
-        self.tweens
+        self.children
             .iter()
-            .map(Tween::total_duration)
+            .map(|child| child.total_duration())
             .max()
             .unwrap_or(0)
     }
+
+    /// Ticks played over the longest child's duration, falling on backward passes.
+    pub fn progress(&self) -> F {
+        pass_progress(
+            self.elapsed,
+            self.total_duration(),
+            self.state,
+            self.direction,
+        )
+    }
+
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Flip direction mid-flight. Children under way reverse in place, and ones the mirrored
+    /// pass has not reached yet wait at their starting end.
+    pub fn reverse(&mut self) {
+        let total = self.total_duration();
+        self.direction = self.direction.reversed();
+        self.elapsed = total - self.elapsed.min(total);
+        let (elapsed, direction) = (self.elapsed, self.direction);
+        for child in &mut self.children {
+            if direction.pass_start(0, child.total_duration(), total) < elapsed {
+                child.reverse();
+            }
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a pass toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+        for child in &mut self.children {
+            child.rewind(direction);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for child in &mut self.children {
+            child.reset();
+        }
+        self.elapsed = 0;
+        self.direction = PlayDirection::Forward;
+        self.state = if self.children.is_empty() {
+            TweenState::Idle
+        } else {
+            TweenState::Playing
+        };
+    }
 }
 
-impl<T: Lerp<F> + Clone, F: Float> Default for Parallel<T, F> {
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Default for Parallel<T, F> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Like parallel but each tween starts with a fixed tick offset.
-#[derive(Clone, Debug)]
-pub struct Stagger<T: Lerp<F>, F: Float> {
-    tweens: Vec<Tween<T, F>>,
-    offset: u32,
+#[cfg(feature = "alloc")]
+impl<T, F: Float> fmt::Debug for Parallel<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Parallel")
+            .field("children", &self.children.len())
+            .field("elapsed", &self.elapsed)
+            .field("state", &self.state)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+/// Like parallel but each animation starts after a delay given by a [`StaggerPattern`].
+///
+/// Children are boxed like [`Sequence`]'s, so they must be `'static`; [`FixedStagger`] holds
+/// them inline instead. Backward passes mirror the start ticks.
+///
+/// [`FixedStagger`]: crate::FixedStagger
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct Stagger<T, F: Float> {
+    children: Vec<Box<dyn DynAnimation<F, T>>>,
+    pattern: StaggerPattern<F>,
+    starts: Vec<u32>,
     elapsed: u32,
     state: TweenState,
+    direction: PlayDirection,
 }
 
-impl<T: Lerp<F> + Clone, F: Float> Stagger<T, F> {
+#[cfg(feature = "alloc")]
+impl<T, F: Float> Stagger<T, F> {
+    /// Each animation starts `offset` ticks after the previous one.
     pub fn new(offset: u32) -> Self {
+        Self::from_pattern(StaggerPattern::each(offset))
+    }
+
+    pub fn from_pattern(pattern: StaggerPattern<F>) -> Self {
         Self {
-            tweens: Vec::new(),
-            offset,
+            children: Vec::new(),
+            pattern,
+            starts: Vec::new(),
             elapsed: 0,
             state: TweenState::Idle,
+            direction: PlayDirection::Forward,
         }
     }
 
-    pub fn push(mut self, tween: Tween<T, F>) -> Self {
-        self.tweens.push(tween);
+    pub fn with_pattern(mut self, pattern: StaggerPattern<F>) -> Self {
+        self.pattern = pattern;
+        self.starts = self.pattern.offsets(self.children.len());
+        self
+    }
+
+    /// Start tick of each animation in a forward pass.
+    pub fn starts(&self) -> &[u32] {
+        &self.starts
+    }
+
+    pub fn push<A>(mut self, animation: A) -> Self
+    where
+        A: Animation<F, Value = T> + Clone + 'static,
+    {
+        self.children.push(Box::new(animation));
+        self.starts = self.pattern.offsets(self.children.len());
         if self.state == TweenState::Idle {
             self.state = TweenState::Playing;
         }
@@ -457,51 +979,167 @@ impl<T: Lerp<F> + Clone, F: Float> Stagger<T, F> {
     }
 
     pub fn tick(&mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.children.len());
+        self.tick_into(&mut values);
+        values
+    }
+
+    /// [`tick`](Self::tick) that appends the values to `out`, e.g. a reused buffer, instead of
+    /// allocating.
+    pub fn tick_into(&mut self, out: &mut impl Extend<T>) {
         if self.state != TweenState::Playing {
-            return self.values();
+            out.extend(self.values_iter());
+            return;
         }
 
-        let mut values = Vec::with_capacity(self.tweens.len());
-        for (idx, tween) in self.tweens.iter_mut().enumerate() {
-            let start = (idx as u32).saturating_mul(self.offset);
-            if self.elapsed >= start {
-                values.push(tween.tick());
+        let (elapsed, total, direction) = (self.elapsed, self.total_duration(), self.direction);
+        let children = self.children.iter_mut().zip(&self.starts);
+        out.extend(children.map(|(child, &start)| {
+            let start = direction.pass_start(start, child.total_duration(), total);
+            if start == elapsed {
+                child.rewind(direction);
+            }
+            if elapsed >= start {
+                child.tick()
             } else {
-                values.push(tween.value());
+                child.value()
             }
-        }
-
-        if self.tweens.iter().all(Tween::is_finished) {
+        }));
+
+        let mut children = self.children.iter().zip(&self.starts);
+        let done = children.all(|(child, &start)| {
+            direction.pass_start(start, child.total_duration(), total) <= elapsed
+                && child.is_finished()
+        });
+        if done {
             self.state = TweenState::Finished;
         } else {
             self.elapsed = self.elapsed.saturating_add(1);
         }
-
-        values
     }
 
     pub fn values(&self) -> Vec<T> {
-        self.tweens.iter().map(Tween::value).collect()
+        self.values_iter().collect()
+    }
+
+    /// Current values, without collecting them.
+    pub fn values_iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.children.iter().map(|child| child.value())
     }
 
     pub fn is_finished(&self) -> bool {
         self.state == TweenState::Finished
     }
 
+    pub fn state(&self) -> TweenState {
+        self.state
+    }
+
     pub fn total_duration(&self) -> u32 {
-        self.tweens
+        self.children
             .iter()
-            .enumerate()
-            .map(|(idx, tween)| (idx as u32).saturating_mul(self.offset) + tween.total_duration())
+            .zip(&self.starts)
+            .map(|(child, &start)| start.saturating_add(child.total_duration()))
             .max()
             .unwrap_or(0)
     }
+
+    /// Falls on backward passes.
+    pub fn progress(&self) -> F {
+        pass_progress(
+            self.elapsed,
+            self.total_duration(),
+            self.state,
+            self.direction,
+        )
+    }
+
+    pub fn direction(&self) -> PlayDirection {
+        self.direction
+    }
+
+    /// Flip direction mid-flight. Children under way reverse in place, and ones the mirrored
+    /// pass has not reached yet wait at their starting end.
+    pub fn reverse(&mut self) {
+        let total = self.total_duration();
+        self.direction = self.direction.reversed();
+        self.elapsed = total - self.elapsed.min(total);
+        let (elapsed, direction) = (self.elapsed, self.direction);
+        for (child, &start) in self.children.iter_mut().zip(&self.starts) {
+            if direction.pass_start(start, child.total_duration(), total) < elapsed {
+                child.reverse();
+            }
+        }
+        if self.state == TweenState::Finished {
+            self.state = TweenState::Playing;
+        }
+    }
+
+    /// Reset and play a pass toward `direction`'s end, starting from the other end.
+    pub fn rewind(&mut self, direction: PlayDirection) {
+        self.reset();
+        self.direction = direction;
+        for child in &mut self.children {
+            child.rewind(direction);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for child in &mut self.children {
+            child.reset();
+        }
+        self.elapsed = 0;
+        self.direction = PlayDirection::Forward;
+        self.state = if self.children.is_empty() {
+            TweenState::Idle
+        } else {
+            TweenState::Playing
+        };
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, F: Float> fmt::Debug for Stagger<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stagger")
+            .field("children", &self.children.len())
+            .field("pattern", &self.pattern)
+            .field("starts", &self.starts)
+            .field("elapsed", &self.elapsed)
+            .field("state", &self.state)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
+
+/// Progress through a pass of `total` ticks, `elapsed` into it, falling when backward.
+pub(crate) fn pass_progress<F: Float>(
+    elapsed: u32,
+    total: u32,
+    state: TweenState,
+    direction: PlayDirection,
+) -> F {
+    let progress = progress_of::<F>(elapsed, total, state);
+    match direction {
+        PlayDirection::Forward => progress,
+        PlayDirection::Backward => F::one() - progress,
+    }
+}
+
+/// `elapsed` over `total`, complete once finished.
+pub(crate) fn progress_of<F: Float>(elapsed: u32, total: u32, state: TweenState) -> F {
+    if total == 0 || state == TweenState::Finished {
+        return F::one();
+    }
+    F::from_f32(elapsed.min(total) as f32 / total as f32)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::{Parallel, Sequence, Stagger, Tween};
-    use crate::{Easing, LoopMode, TweenState};
+    use crate::{
+        Easing, LoopMode, PlayDirection, StaggerFrom, StaggerPattern, TweenError, TweenState,
+    };
 
     const EPS: f32 = 1e-4;
 
@@ -586,6 +1224,20 @@ mod tests {
         assert!(approx(value, 10.0));
     }
 
+    #[test]
+    fn tween_reverse_mid_flight() {
+        let mut tween = Tween::new(0.0f32, 10.0, 10);
+        for _ in 0..4 {
+            tween.tick();
+        }
+        tween.reverse();
+        assert_eq!(tween.direction(), PlayDirection::Backward);
+        assert!(approx(tween.value(), 4.0));
+        assert!(approx(tween.tick(), 3.0));
+        tween.set_direction(PlayDirection::Forward);
+        assert!(approx(tween.tick(), 4.0));
+    }
+
     #[test]
     fn tween_pause_resume() {
         let mut tween = Tween::new(0.0f32, 100.0, 10);
@@ -663,6 +1315,102 @@ mod tests {
         assert!(approx(transition, 10.0));
     }
 
+    #[test]
+    fn sequence_ping_pong_walks_children_backward() {
+        let mut seq = Sequence::new()
+            .push(Tween::new(0.0f32, 10.0, 2))
+            .push(Tween::new(10.0, 20.0, 2))
+            .with_loop(LoopMode::PingPongCount(1));
+        let mut values = [0.0f32; 8];
+        for value in &mut values {
+            *value = seq.tick();
+        }
+        let expected = [5.0, 10.0, 15.0, 20.0, 15.0, 10.0, 5.0, 0.0];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(approx(*value, expected));
+        }
+        assert!(seq.is_finished());
+    }
+
+    #[test]
+    fn sequence_reverse_mid_flight() {
+        let mut seq = Sequence::new()
+            .push(Tween::new(0.0f32, 10.0, 2))
+            .push(Tween::new(10.0, 20.0, 2));
+        for _ in 0..3 {
+            seq.tick();
+        }
+        seq.reverse();
+        assert!(approx(seq.value(), 15.0));
+        assert!(approx(seq.progress(), 0.75));
+        assert!(approx(seq.tick(), 10.0));
+        assert!(approx(seq.tick(), 5.0));
+        assert!(approx(seq.tick(), 0.0));
+        assert!(seq.is_finished());
+    }
+
+    #[test]
+    fn sequence_gap_holds_previous_value() {
+        let mut seq = Sequence::new()
+            .push(Tween::new(0.0f32, 10.0, 2))
+            .push_offset(Tween::new(10.0, 20.0, 2), 2)
+            .with_loop(LoopMode::PingPongCount(1));
+        assert_eq!(seq.total_duration(), 6);
+        let mut values = [0.0f32; 12];
+        for value in &mut values {
+            *value = seq.tick();
+        }
+        let expected = [
+            5.0, 10.0, 10.0, 10.0, 15.0, 20.0, 15.0, 10.0, 10.0, 10.0, 5.0, 0.0,
+        ];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(approx(*value, expected));
+        }
+        assert!(seq.is_finished());
+    }
+
+    #[test]
+    fn sequence_overlap_shows_later_child() {
+        let mut seq = Sequence::new()
+            .push(Tween::new(0.0f32, 10.0, 4))
+            .push_offset(Tween::new(100.0, 200.0, 4), -2);
+        assert_eq!(seq.total_duration(), 6);
+        assert!(approx(seq.value_at(3), 125.0));
+        assert!(approx(seq.value(), 0.0));
+
+        let mut values = [0.0f32; 6];
+        for value in &mut values {
+            *value = seq.tick();
+        }
+        let expected = [2.5, 5.0, 125.0, 150.0, 175.0, 200.0];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(approx(*value, expected));
+        }
+        assert!(seq.is_finished());
+    }
+
+    #[test]
+    fn sequence_seek_to_label() {
+        let mut seq = Sequence::new()
+            .push(Tween::new(0.0f32, 10.0, 2))
+            .with_label("second")
+            .push_offset(Tween::new(10.0, 20.0, 2), 2)
+            .with_label("end");
+        assert_eq!(seq.label_tick("second"), Some(4));
+        assert_eq!(seq.label_tick("end"), Some(6));
+        assert_eq!(seq.label_tick("missing"), None);
+
+        seq.seek_label("second").unwrap();
+        assert!(approx(seq.value(), 10.0));
+        assert!(approx(seq.progress(), 4.0 / 6.0));
+        assert!(approx(seq.tick(), 15.0));
+        assert_eq!(seq.seek_label("missing"), Err(TweenError::UnknownLabel));
+
+        seq.seek_label("end").unwrap();
+        assert!(seq.is_finished());
+        assert!(approx(seq.value(), 20.0));
+    }
+
     #[test]
     fn parallel_finishes_with_longest() {
         let mut parallel = Parallel::new()
@@ -676,6 +1424,30 @@ mod tests {
         assert!(parallel.is_finished());
     }
 
+    #[test]
+    fn parallel_and_stagger_tick_into_buffer() {
+        let mut parallel = Parallel::new()
+            .push(Tween::new(0.0f32, 4.0, 4))
+            .push(Tween::new(4.0f32, 0.0, 4));
+        let mut buffer = alloc::vec::Vec::with_capacity(2);
+        parallel.tick_into(&mut buffer);
+        assert_eq!(buffer, [1.0, 3.0]);
+        buffer.clear();
+        parallel.tick_into(&mut buffer);
+        assert_eq!(
+            buffer,
+            parallel.values_iter().collect::<alloc::vec::Vec<_>>()
+        );
+
+        let mut stagger = Stagger::new(1)
+            .push(Tween::new(0.0f32, 2.0, 2))
+            .push(Tween::new(0.0f32, 2.0, 2));
+        buffer.clear();
+        stagger.tick_into(&mut buffer);
+        stagger.tick_into(&mut buffer);
+        assert_eq!(buffer, [1.0, 0.0, 2.0, 1.0]);
+    }
+
     #[test]
     fn parallel_returns_all_values() {
         let mut parallel = Parallel::new()
@@ -707,4 +1479,77 @@ mod tests {
             .push(Tween::new(0.0f32, 1.0, 1));
         assert_eq!(stagger.total_duration(), 7);
     }
+
+    #[test]
+    fn stagger_pattern_from_center() {
+        let mut stagger = (0..5).fold(
+            Stagger::from_pattern(StaggerPattern::amount(4).with_from(StaggerFrom::Center)),
+            |stagger, _| stagger.push(Tween::new(0.0f32, 1.0, 4)),
+        );
+        assert_eq!(stagger.starts(), [4, 2, 0, 2, 4]);
+        assert_eq!(stagger.total_duration(), 8);
+        let mut values = stagger.values();
+        for _ in 0..3 {
+            values = stagger.tick();
+        }
+        assert_eq!(values, [0.0, 0.25, 0.75, 0.25, 0.0]);
+    }
+
+    #[test]
+    fn parallel_and_stagger_play_back_in_ping_pong() {
+        let parallel = Parallel::new()
+            .push(Tween::new(0.0f32, 4.0, 4))
+            .push(Tween::new(0.0f32, 2.0, 2));
+        let mut seq = Sequence::new()
+            .push(parallel)
+            .with_loop(LoopMode::PingPongCount(1));
+        let mut values = alloc::vec::Vec::new();
+        while !seq.is_finished() {
+            values.push(seq.tick());
+        }
+        assert_eq!(
+            values,
+            [
+                [1.0, 1.0],
+                [2.0, 2.0],
+                [3.0, 2.0],
+                [4.0, 2.0],
+                [3.0, 2.0],
+                [2.0, 2.0],
+                [1.0, 1.0],
+                [0.0, 0.0]
+            ]
+        );
+
+        let mut stagger = Stagger::new(2)
+            .push(Tween::new(0.0f32, 2.0, 2))
+            .push(Tween::new(0.0f32, 2.0, 2));
+        for _ in 0..3 {
+            stagger.tick();
+        }
+        stagger.reverse();
+        assert_eq!(stagger.direction(), PlayDirection::Backward);
+        let mut values = alloc::vec::Vec::new();
+        while !stagger.is_finished() {
+            values.push(stagger.tick());
+        }
+        assert_eq!(values, [[2.0, 0.0], [1.0, 0.0], [0.0, 0.0]]);
+    }
+
+    #[test]
+    fn compositions_hold_values_without_debug() {
+        #[derive(Clone, Copy, PartialEq)]
+        struct Level(f32);
+
+        impl crate::Lerp<f32> for Level {
+            fn lerp(&self, other: &Self, t: f32) -> Self {
+                Level(self.0.lerp(&other.0, t))
+            }
+        }
+
+        let seq = Sequence::new().push(Tween::new(Level(0.0), Level(1.0), 2));
+        let parallel = Parallel::new().push(seq.clone());
+        assert!(alloc::format!("{seq:?}").contains("children: 1"));
+        assert!(alloc::format!("{parallel:?}").starts_with("Parallel"));
+    }
 }
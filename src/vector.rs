@@ -0,0 +1,135 @@
+use crate::float::Float;
+
+/// Trait for types that support the vector arithmetic used by springs and spline curves.
+///
+/// Implemented for scalars, tuples and arrays of vector types, and (behind cargo features)
+/// for `glam`, `mint` and `nalgebra` vectors.
+pub trait VectorSpace<F: Float>: Clone {
+    /// The additive identity.
+    fn zero() -> Self;
+    /// Component-wise sum.
+    fn plus(&self, other: &Self) -> Self;
+    /// Component-wise difference.
+    fn minus(&self, other: &Self) -> Self;
+    /// Multiply every component by `factor`.
+    fn scaled(&self, factor: F) -> Self;
+    /// Inner product.
+    fn dot(&self, other: &Self) -> F;
+
+    /// Euclidean length.
+    fn length(&self) -> F {
+        self.dot(self).sqrt()
+    }
+}
+
+impl<F: Float> VectorSpace<F> for F {
+    fn zero() -> Self {
+        F::zero()
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        *self + *other
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        *self - *other
+    }
+
+    fn scaled(&self, factor: F) -> Self {
+        *self * factor
+    }
+
+    fn dot(&self, other: &Self) -> F {
+        *self * *other
+    }
+
+    fn length(&self) -> F {
+        self.abs()
+    }
+}
+
+macro_rules! impl_vector_tuple {
+    ($($name:ident $idx:tt),+) => {
+        impl<F: Float, $($name: VectorSpace<F>),+> VectorSpace<F> for ($($name,)+) {
+            fn zero() -> Self {
+                ($($name::zero(),)+)
+            }
+
+            fn plus(&self, other: &Self) -> Self {
+                ($(self.$idx.plus(&other.$idx),)+)
+            }
+
+            fn minus(&self, other: &Self) -> Self {
+                ($(self.$idx.minus(&other.$idx),)+)
+            }
+
+            fn scaled(&self, factor: F) -> Self {
+                ($(self.$idx.scaled(factor),)+)
+            }
+
+            fn dot(&self, other: &Self) -> F {
+                F::zero() $(+ self.$idx.dot(&other.$idx))+
+            }
+        }
+    };
+}
+
+impl_vector_tuple!(A 0);
+impl_vector_tuple!(A 0, B 1);
+impl_vector_tuple!(A 0, B 1, C 2);
+impl_vector_tuple!(A 0, B 1, C 2, D 3);
+
+impl<F: Float, T: VectorSpace<F>, const N: usize> VectorSpace<F> for [T; N] {
+    fn zero() -> Self {
+        core::array::from_fn(|_| T::zero())
+    }
+
+    fn plus(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].plus(&other[i]))
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| self[i].minus(&other[i]))
+    }
+
+    fn scaled(&self, factor: F) -> Self {
+        core::array::from_fn(|i| self[i].scaled(factor))
+    }
+
+    fn dot(&self, other: &Self) -> F {
+        self.iter()
+            .zip(other.iter())
+            .fold(F::zero(), |acc, (a, b)| acc + a.dot(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VectorSpace;
+
+    const EPS: f32 = 1e-6;
+
+    #[test]
+    fn vector_scalar_ops() {
+        assert_eq!(VectorSpace::plus(&2.0f32, &3.0), 5.0);
+        assert_eq!(VectorSpace::minus(&2.0f32, &3.0), -1.0);
+        assert_eq!(3.0f32.scaled(2.0), 6.0);
+        assert_eq!((-4.0f32).length(), 4.0);
+    }
+
+    #[test]
+    fn vector_tuple_length() {
+        let v = (3.0f32, 4.0f32);
+        assert!((v.length() - 5.0).abs() < EPS);
+        assert_eq!(v.minus(&(1.0, 1.0)), (2.0, 3.0));
+        assert_eq!(<(f32, f32)>::zero(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn vector_array_ops() {
+        let a = [1.0f32, 2.0, 2.0];
+        assert!((a.length() - 3.0).abs() < EPS);
+        assert_eq!(a.scaled(2.0), [2.0, 4.0, 4.0]);
+        assert_eq!(a.plus(&[1.0, 1.0, 1.0]), [2.0, 3.0, 3.0]);
+    }
+}